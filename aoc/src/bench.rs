@@ -0,0 +1,141 @@
+use common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timings of every stage, in the order of `STAGES`
+pub type Samples = [Vec<Duration>; 3];
+
+/// Times parse, part 1 and part 2 of a day over the given number of iterations
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> Result<Samples, ParseError> {
+    let mut samples: Samples = Default::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        samples[2].push(start.elapsed());
+    }
+
+    Ok(samples)
+}
+
+/// Summary of the samples of one stage, in nanoseconds
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|nano| (nano - mean).powi(2)).sum::<f64>() / count;
+        let middle = nanos.len() / 2;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2.0
+        } else {
+            nanos[middle]
+        };
+
+        Stats {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Stats keyed by day and stage name
+pub type Baseline = HashMap<(u8, String), Stats>;
+
+fn parse_baseline_line(line: &str) -> Option<((u8, String), Stats)> {
+    let columns: Vec<&str> = line.split('\t').collect();
+
+    let [day, stage, mean, median, stddev] = columns.as_slice() else {
+        return None;
+    };
+
+    Some((
+        (day.parse().ok()?, String::from(*stage)),
+        Stats {
+            mean: mean.parse().ok()?,
+            median: median.parse().ok()?,
+            stddev: stddev.parse().ok()?,
+        },
+    ))
+}
+
+/// Loads a baseline saved by `save_baseline`, or an empty one if the file does not exist yet
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Baseline::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut baseline = Baseline::new();
+
+    for line in contents.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+
+        let Some((key, stats)) = parse_baseline_line(line) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Malformed baseline line '{}'", line),
+            ));
+        };
+
+        baseline.insert(key, stats);
+    }
+
+    Ok(baseline)
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut entries: Vec<(&(u8, String), &Stats)> = baseline.iter().collect();
+    entries.sort_by_key(|((day, stage), _)| (*day, STAGES.iter().position(|known| known == stage)));
+
+    let mut contents = String::from("# day\tstage\tmean_ns\tmedian_ns\tstddev_ns\n");
+
+    for ((day, stage), stats) in entries {
+        contents.push_str(&format!(
+            "{}\t{}\t{:.0}\t{:.0}\t{:.0}\n",
+            day, stage, stats.mean, stats.median, stats.stddev
+        ));
+    }
+
+    fs::write(path, contents)
+}
+
+/// Relative change of the median against the baseline, in percent, or `None` if the
+/// baseline median is zero
+pub fn change_percent(current: &Stats, previous: &Stats) -> Option<f64> {
+    (previous.median != 0.0).then(|| (current.median - previous.median) / previous.median * 100.0)
+}
+
+pub fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
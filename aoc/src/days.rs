@@ -0,0 +1,39 @@
+use crate::bench::{Samples, measure};
+use common::input::{InputNotFound, locate_input};
+use common::{ParseError, Part, Solution, solve_part};
+use std::path::PathBuf;
+
+pub struct Day {
+    pub number: u8,
+    /// Resolves the default puzzle input of the day
+    pub locate_input: fn() -> Result<PathBuf, InputNotFound>,
+    /// Solves the given part of the puzzle input text
+    pub solve: fn(&str, Part) -> Result<String, ParseError>,
+    /// Times every stage of the day over the given number of iterations
+    pub bench: fn(&str, usize) -> Result<Samples, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            locate_input: locate_input::<S>,
+            solve: solve_part::<S>,
+            bench: measure::<S>,
+        }
+    }
+}
+
+pub const DAYS: [Day; 7] = [
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_3::Day3>(),
+    Day::of::<day_4::Day4>(),
+    Day::of::<day_5::Day5>(),
+    Day::of::<day_6::Day6>(),
+    Day::of::<day_7::Day7>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
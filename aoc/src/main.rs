@@ -0,0 +1,382 @@
+mod bench;
+mod days;
+
+use bench::{Baseline, STAGES, Stats, change_percent, format_nanos, load_baseline, save_baseline};
+use common::Part;
+use common::cli::{parse_options, parse_value};
+use common::input::InputSource;
+use common::json::{Json, render_lines};
+use days::{DAYS, Day, find_day};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage:
+    aoc run --day N [--part P] [--input PATH|-] [--format table|json]
+    aoc run --all [--format table|json]
+    aoc bench [--day N] [--iterations N] [--baseline PATH] [--threshold PERCENT]
+              [--update-baseline]
+
+The baseline is only updated when no stage regressed, unless --update-baseline is given.";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_BASELINE: &str = "bench_baseline.tsv";
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+struct Answer {
+    day: u8,
+    part: Part,
+    value: String,
+    /// Time spent parsing the input and solving the part
+    duration: Duration,
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Table,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+enum Selection {
+    All,
+    Day {
+        number: u8,
+        part: Option<Part>,
+        input: Option<String>,
+    },
+}
+
+struct BenchOptions {
+    day: Option<u8>,
+    iterations: usize,
+    baseline: PathBuf,
+    /// Allowed slowdown of the median against the baseline, in percent
+    threshold: f64,
+    /// Whether to save the results as the new baseline even if some stage regressed
+    update_baseline: bool,
+}
+
+enum Command {
+    Run(Selection, Format),
+    Bench(BenchOptions),
+}
+
+fn parse_run_args(options: &[String]) -> Result<(Selection, Format), String> {
+    let mut all = false;
+    let mut format = Format::Table;
+    let mut number: Option<u8> = None;
+    let mut part: Option<Part> = None;
+    let mut input: Option<String> = None;
+
+    let with_value = ["--day", "--part", "--input", "--format"];
+    for (option, value) in parse_options(options, &with_value)? {
+        match (option, value) {
+            ("--all", None) => all = true,
+            ("--format", Some(value)) => format = parse_value(option, value)?,
+            ("--day", Some(value)) => number = Some(parse_value(option, value)?),
+            ("--part", Some(value)) => part = Some(value.parse()?),
+            ("--input", Some(value)) => input = Some(String::from(value)),
+            _ => return Err(format!("Unknown option '{}'", option)),
+        }
+    }
+
+    let selection = match (all, number) {
+        (true, None) if part.is_none() && input.is_none() => Selection::All,
+        (true, _) => return Err(String::from("--all cannot be combined with other options")),
+        (false, Some(number)) => Selection::Day {
+            number,
+            part,
+            input,
+        },
+        (false, None) => return Err(String::from("Either --day or --all is required")),
+    };
+
+    Ok((selection, format))
+}
+
+fn parse_bench_args(options: &[String]) -> Result<BenchOptions, String> {
+    let mut bench_options = BenchOptions {
+        day: None,
+        iterations: DEFAULT_ITERATIONS,
+        baseline: PathBuf::from(DEFAULT_BASELINE),
+        threshold: DEFAULT_THRESHOLD,
+        update_baseline: false,
+    };
+
+    let with_value = ["--day", "--iterations", "--baseline", "--threshold"];
+    for (option, value) in parse_options(options, &with_value)? {
+        match (option, value) {
+            ("--day", Some(value)) => bench_options.day = Some(parse_value(option, value)?),
+            ("--iterations", Some(value)) => bench_options.iterations = parse_value(option, value)?,
+            ("--baseline", Some(value)) => bench_options.baseline = PathBuf::from(value),
+            ("--threshold", Some(value)) => bench_options.threshold = parse_value(option, value)?,
+            ("--update-baseline", None) => bench_options.update_baseline = true,
+            _ => return Err(format!("Unknown option '{}'", option)),
+        }
+    }
+
+    if bench_options.iterations == 0 {
+        return Err(String::from("--iterations must be at least 1"));
+    }
+
+    Ok(bench_options)
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, options)) if command == "run" => {
+            parse_run_args(options).map(|(selection, format)| Command::Run(selection, format))
+        }
+        Some((command, options)) if command == "bench" => {
+            parse_bench_args(options).map(Command::Bench)
+        }
+        Some((command, _)) => Err(format!("Unknown command '{}'", command)),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+fn lookup_day(number: u8) -> &'static Day {
+    find_day(number).unwrap_or_else(|| {
+        eprintln!("Day {} is not solved yet", number);
+        process::exit(2);
+    })
+}
+
+/// Reads the input of a day from the given path (`-` for stdin), or from its default input
+fn read_input(day: &Day, path: Option<&str>) -> (InputSource, String) {
+    let source = match path {
+        Some(path) => InputSource::from_arg(path),
+        None => InputSource::File((day.locate_input)().unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            process::exit(1);
+        })),
+    };
+
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!(
+            "Could not read input {} for day {}: {}",
+            source, day.number, error
+        );
+        process::exit(1);
+    });
+
+    (source, input)
+}
+
+fn solve_parts(day: &Day, parts: &[Part], path: Option<&str>) -> Vec<Answer> {
+    let (source, input) = read_input(day, path);
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = (day.solve)(&input, part).unwrap_or_else(|error| {
+                eprintln!("{}", error.with_file(source.to_string()).render());
+                process::exit(1);
+            });
+
+            Answer {
+                day: day.number,
+                part,
+                value,
+                duration: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+fn print_summary(answers: &[Answer]) {
+    let answer_width = answers
+        .iter()
+        .map(|answer| answer.value.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("Day | Part | Answer");
+    println!("----+------+-{}", "-".repeat(answer_width));
+
+    for answer in answers {
+        println!("{:>3} | {:>4} | {}", answer.day, answer.part, answer.value);
+    }
+}
+
+/// Prints one `{day, part, answer, duration}` record per answer. The answer is a string
+/// so that it keeps its precision, the duration is given in nanoseconds.
+fn print_json(answers: &[Answer]) {
+    let records: Vec<Json> = answers
+        .iter()
+        .map(|answer| {
+            Json::object([
+                ("day", Json::from(answer.day)),
+                ("part", Json::from(answer.part.number())),
+                ("answer", Json::from(answer.value.as_str())),
+                ("duration", Json::from(answer.duration.as_nanos())),
+            ])
+        })
+        .collect();
+
+    println!("{}", render_lines(&records));
+}
+
+fn run(selection: Selection, format: Format) {
+    let answers = match selection {
+        Selection::All => DAYS
+            .iter()
+            .flat_map(|day| solve_parts(day, &Part::BOTH, None))
+            .collect(),
+        Selection::Day {
+            number,
+            part,
+            input,
+        } => {
+            let parts = match &part {
+                Some(part) => std::slice::from_ref(part),
+                None => &Part::BOTH,
+            };
+
+            solve_parts(lookup_day(number), parts, input.as_deref())
+        }
+    };
+
+    match format {
+        Format::Table => print_summary(&answers),
+        Format::Json => print_json(&answers),
+    }
+}
+
+/// Benchmarks the selected days, reports them against the previous baseline and
+/// stores the new results in the baseline file, unless a stage regressed and
+/// `--update-baseline` was not given. Returns whether any stage regressed.
+fn bench(options: &BenchOptions) -> bool {
+    let days: Vec<&Day> = match options.day {
+        Some(number) => vec![lookup_day(number)],
+        None => DAYS.iter().collect(),
+    };
+
+    let baseline = load_baseline(&options.baseline).unwrap_or_else(|error| {
+        eprintln!(
+            "Could not load baseline {}: {}",
+            options.baseline.display(),
+            error
+        );
+        process::exit(1);
+    });
+
+    let mut results: Vec<(u8, &str, Stats)> = vec![];
+
+    for day in days {
+        let (source, input) = read_input(day, None);
+        let samples = (day.bench)(&input, options.iterations).unwrap_or_else(|error| {
+            eprintln!("{}", error.with_file(source.to_string()).render());
+            process::exit(1);
+        });
+
+        for (stage, stage_samples) in STAGES.iter().zip(&samples) {
+            results.push((day.number, stage, Stats::from_samples(stage_samples)));
+        }
+    }
+
+    let regressed = print_bench_report(&results, &baseline, options);
+
+    if regressed && !options.update_baseline {
+        eprintln!(
+            "Baseline {} not updated because of regressions, pass --update-baseline to accept them",
+            options.baseline.display()
+        );
+        return regressed;
+    }
+
+    // Days that were not benchmarked this time keep their previous baseline
+    let mut updated_baseline = baseline;
+    for (day, stage, stats) in results {
+        updated_baseline.insert((day, String::from(stage)), stats);
+    }
+
+    save_baseline(&options.baseline, &updated_baseline).unwrap_or_else(|error| {
+        eprintln!(
+            "Could not save baseline {}: {}",
+            options.baseline.display(),
+            error
+        );
+        process::exit(1);
+    });
+
+    regressed
+}
+
+fn print_bench_report(
+    results: &[(u8, &str, Stats)],
+    baseline: &Baseline,
+    options: &BenchOptions,
+) -> bool {
+    let mut regressed = false;
+
+    println!(
+        "{} iterations, regression threshold {}%",
+        options.iterations, options.threshold
+    );
+    println!(
+        "Day | Stage | {:>12} | {:>12} | {:>12} | Baseline",
+        "Mean", "Median", "Stddev"
+    );
+    println!("----+-------+-{0}-+-{0}-+-{0}-+---------", "-".repeat(12));
+
+    for (day, stage, stats) in results {
+        let comparison = match baseline.get(&(*day, String::from(*stage))) {
+            Some(previous) => match change_percent(stats, previous) {
+                Some(change) if change > options.threshold => {
+                    regressed = true;
+                    format!("{:+.1}% REGRESSION", change)
+                }
+                Some(change) => format!("{:+.1}%", change),
+                None => String::from("n/a"),
+            },
+            None => String::from("-"),
+        };
+
+        println!(
+            "{:>3} | {:<5} | {:>12} | {:>12} | {:>12} | {}",
+            day,
+            stage,
+            format_nanos(stats.mean),
+            format_nanos(stats.median),
+            format_nanos(stats.stddev),
+            comparison
+        );
+    }
+
+    regressed
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Run(selection, format) => run(selection, format),
+        Command::Bench(options) => {
+            if bench(&options) {
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+pub fn parse_value<T: FromStr>(option: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{}' for {}", value, option))
+}
+
+/// Splits the arguments into flags, positional arguments and `--option value` pairs,
+/// given the options taking a value
+pub fn parse_options<'a>(
+    options: &'a [String],
+    with_value: &[&str],
+) -> Result<Vec<(&'a str, Option<&'a str>)>, String> {
+    let mut parsed = vec![];
+    let mut options = options.iter();
+
+    while let Some(option) = options.next() {
+        if with_value.contains(&option.as_str()) {
+            let value = options
+                .next()
+                .ok_or_else(|| format!("Missing value for {}", option))?;
+
+            parsed.push((option.as_str(), Some(value.as_str())));
+        } else {
+            parsed.push((option.as_str(), None));
+        }
+    }
+
+    Ok(parsed)
+}
@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+/// Renders a value as a CSV field, quoting it when it contains a separator, quote or newline
+pub fn field(value: impl Display) -> String {
+    let value = value.to_string();
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Renders one CSV line, including the trailing newline
+pub fn row(fields: &[String]) -> String {
+    let fields: Vec<String> = fields.iter().map(field).collect();
+
+    format!("{}\n", fields.join(","))
+}
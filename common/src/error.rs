@@ -0,0 +1,106 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A parse failure pointing at the offending text of the puzzle input
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Input file, only known once the error reaches whoever read the file
+    pub file: Option<PathBuf>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// The offending text itself
+    pub text: String,
+    /// The whole line the offending text was found on
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for the `len` bytes at byte `offset` of `source_line` on the
+    /// given line. Panics unless they are whole characters of the line.
+    pub fn at(
+        line: usize,
+        source_line: &str,
+        offset: usize,
+        len: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let text = source_line.get(offset..offset + len).unwrap_or_else(|| {
+            panic!(
+                "Bytes {}..{} are not text of line {}",
+                offset,
+                offset + len,
+                line
+            )
+        });
+
+        ParseError {
+            file: None,
+            line,
+            column: source_line[..offset].chars().count() + 1,
+            text: String::from(text),
+            source_line: String::from(source_line),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error for the whole line, without the whitespace around it
+    pub fn at_line(line: usize, source_line: &str, message: impl Into<String>) -> Self {
+        let offset = source_line.len() - source_line.trim_start().len();
+        ParseError::at(line, source_line, offset, source_line.trim().len(), message)
+    }
+
+    /// Creates an error that is not tied to any text, e.g. for an empty input
+    pub fn empty(message: impl Into<String>) -> Self {
+        ParseError::at(1, "", 0, 0, message)
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Renders the error with the source line and a caret under the offending text
+    pub fn render(&self) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let carets = "^".repeat(self.text.chars().count().max(1));
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        };
+
+        format!(
+            "error: {}\n{} --> {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter,
+            location,
+            gutter,
+            line_number,
+            self.source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            carets
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,225 @@
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A grid cell that can be read from and rendered back to a single character
+pub trait Cell: Sized {
+    fn from_char(char: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(char: char) -> Option<Self> {
+        Some(char)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Which cells count as neighbours of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// N, W, E and S
+    Four,
+    /// The four orthogonal neighbours plus the diagonals
+    Eight,
+}
+
+impl Neighbourhood {
+    /// Offsets of the neighbours as (row, column)
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Neighbourhood::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Neighbourhood::Eight => &[
+                (-1, -1), // NW
+                (-1, 0),  // N
+                (-1, 1),  // NE
+                (0, -1),  // W
+                (0, 1),   // E
+                (1, -1),  // SW
+                (1, 0),   // S
+                (1, 1),   // SE
+            ],
+        }
+    }
+}
+
+/// Rectangular grid of cells addressed by (row, column)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, or returns `None` if they differ in width
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "Row {} out of bounds", row);
+
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {} out of bounds", col);
+
+        (0..self.height).map(move |row| &self[(row, col)])
+    }
+
+    /// Every (row, column) of the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Positions of the neighbours of a cell that lie within the grid
+    pub fn neighbours(
+        &self,
+        row: usize,
+        col: usize,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |&(relative_row, relative_col)| {
+                let neighbour_row = row.checked_add_signed(relative_row)?;
+                let neighbour_col = col.checked_add_signed(relative_col)?;
+
+                (neighbour_row < height && neighbour_col < width)
+                    .then_some((neighbour_row, neighbour_col))
+            })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("Cell ({}, {}) out of bounds", row, col))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("Cell ({}, {}) out of bounds", row, col))
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    /// Parses one row per line, rejecting empty input, rows of differing width and
+    /// characters that are not a valid cell
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let lines: Vec<&str> = text.lines().collect();
+
+        if lines.first().is_none_or(|line| line.is_empty()) {
+            return Err(ParseError::empty("Empty grid"));
+        }
+
+        let width = lines[0].chars().count();
+        let mut cells: Vec<T> = Vec::with_capacity(width * lines.len());
+
+        for (index, line) in lines.iter().enumerate() {
+            let row_width = line.chars().count();
+
+            if row_width != width {
+                return Err(ParseError::at(
+                    index + 1,
+                    line,
+                    0,
+                    line.len(),
+                    format!("Expected a row of width {}, found {}", width, row_width),
+                ));
+            }
+
+            for (position, char) in line.char_indices() {
+                let cell = T::from_char(char).ok_or_else(|| {
+                    ParseError::at(index + 1, line, position, char.len_utf8(), "Unknown cell")
+                })?;
+
+                cells.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    /// Renders the grid back to text, one line per row
+    pub fn render(&self) -> String {
+        self.rows()
+            .map(|row| row.iter().map(Cell::to_char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Cell> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{ParseError, Solution};
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Environment variable pointing to a directory holding the puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// None of the candidate locations contained the requested input
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub day: u8,
+    pub file: String,
+    pub tried: Vec<PathBuf>,
+}
+
+impl fmt::Display for InputNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "No {} found for day {}, tried:", self.file, self.day)?;
+
+        for path in &self.tried {
+            writeln!(f, "  - {}", path.display())?;
+        }
+
+        write!(f, "Set {} to override the input directory", INPUT_DIR_VAR)
+    }
+}
+
+impl std::error::Error for InputNotFound {}
+
+fn is_workspace_root(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("Cargo.toml"))
+        .map(|manifest| manifest.contains("[workspace]"))
+        .unwrap_or(false)
+}
+
+/// Every path the input of a day may live at, in the order they are tried:
+/// the `AOC_INPUT_DIR` override (with and without a `day-N` subdirectory),
+/// the crate's manifest directory and the workspace root above the current directory
+pub fn candidate_paths(day: u8, file: &str, manifest_dir: &str) -> Vec<PathBuf> {
+    let day_dir = format!("day-{}", day);
+    let mut candidates: Vec<PathBuf> = vec![];
+
+    if let Some(input_dir) = env::var_os(INPUT_DIR_VAR) {
+        let input_dir = PathBuf::from(input_dir);
+
+        candidates.push(input_dir.join(&day_dir).join(file));
+        candidates.push(input_dir.join(file));
+    }
+
+    candidates.push(Path::new(manifest_dir).join(file));
+
+    if let Ok(current_dir) = env::current_dir()
+        && let Some(workspace_root) = current_dir.ancestors().find(|dir| is_workspace_root(dir))
+    {
+        candidates.push(workspace_root.join(&day_dir).join(file));
+    }
+
+    candidates.dedup();
+    candidates
+}
+
+/// Resolves the default input of a day independently of the current working directory
+pub fn locate_input<S: Solution>() -> Result<PathBuf, InputNotFound> {
+    let tried = candidate_paths(S::DAY, S::INPUT, S::MANIFEST_DIR);
+
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(InputNotFound {
+            day: S::DAY,
+            file: String::from(S::INPUT),
+            tried,
+        }),
+    }
+}
+
+/// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` stands for stdin
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    /// Opens the input for reading it line by line instead of all at once
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => f.write_str("<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Uses the given path (or `-` for stdin) if there is one, the day's default input otherwise
+pub fn resolve_source<S: Solution>(arg: Option<&str>) -> Result<InputSource, InputNotFound> {
+    match arg {
+        Some(arg) => Ok(InputSource::from_arg(arg)),
+        None => locate_input::<S>().map(InputSource::File),
+    }
+}
+
+/// Reads and parses the input given on the command line (or the day's default input),
+/// exiting with a diagnostic when it cannot be found, read or parsed
+pub fn load_input<S: Solution>(arg: Option<&str>) -> S::Input {
+    load_input_with::<S, _>(arg, S::parse)
+}
+
+/// Like [`load_input`], but parses the input with `parse` instead of the day's parser
+pub fn load_input_with<S: Solution, T>(
+    arg: Option<&str>,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> T {
+    let source = resolve_source::<S>(arg).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!("Could not read input {}: {}", source, error);
+        process::exit(1);
+    });
+
+    parse(&input).unwrap_or_else(|error| {
+        eprintln!("{}", error.with_file(source.to_string()).render());
+        process::exit(1);
+    })
+}
@@ -0,0 +1,76 @@
+pub mod cli;
+pub mod csv;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod testing;
+
+pub use error::ParseError;
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A single day of the puzzle, split into a typed parse step and the two parts
+pub trait Solution {
+    /// Day number as used by the runner (`--day N`)
+    const DAY: u8;
+    /// File name of the default puzzle input inside the day's crate
+    const INPUT: &'static str;
+    /// Directory of the day's crate, i.e. `env!("CARGO_MANIFEST_DIR")`
+    const MANIFEST_DIR: &'static str;
+
+    /// Parsed representation of the puzzle input shared by both parts
+    type Input;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer;
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+/// One of the two parts of a day's puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Self, Self::Err> {
+        match part {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Part must be 1 or 2, got {}", part)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.number(), f)
+    }
+}
+
+/// Parses the input and solves the given part, rendering the answer as text
+pub fn solve_part<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(match part {
+        Part::One => S::part1(&parsed).to_string(),
+        Part::Two => S::part2(&parsed).to_string(),
+    })
+}
@@ -0,0 +1,73 @@
+use crate::Solution;
+use std::fs;
+use std::path::Path;
+
+/// Name of the per-day manifest listing the expected answers of each input
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Expected answers of both parts for one input file of a day
+#[derive(Debug, PartialEq)]
+pub struct ExpectedAnswers {
+    pub input: String,
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Parses a manifest of `<input file> <part 1 answer> <part 2 answer>` lines,
+/// skipping blank lines and `#` comments
+pub fn parse_manifest(manifest: &str) -> Vec<ExpectedAnswers> {
+    manifest
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+
+            if columns.len() != 3 {
+                panic!("Manifest line needs 3 columns: {}", line);
+            }
+
+            ExpectedAnswers {
+                input: String::from(columns[0]),
+                part1: String::from(columns[1]),
+                part2: String::from(columns[2]),
+            }
+        })
+        .collect()
+}
+
+/// Solves every input listed in the day's `answers.txt` and asserts both answers
+pub fn check_answers<S: Solution>() {
+    let manifest_dir = Path::new(S::MANIFEST_DIR);
+    let manifest = fs::read_to_string(manifest_dir.join(ANSWERS_FILE))
+        .unwrap_or_else(|error| panic!("No {} for day {}: {}", ANSWERS_FILE, S::DAY, error));
+    let expected_answers = parse_manifest(&manifest);
+
+    assert!(
+        !expected_answers.is_empty(),
+        "{} lists no inputs",
+        ANSWERS_FILE
+    );
+
+    for expected in expected_answers {
+        let input = fs::read_to_string(manifest_dir.join(&expected.input))
+            .unwrap_or_else(|error| panic!("Could not read {}: {}", expected.input, error));
+        let parsed = S::parse(&input)
+            .unwrap_or_else(|error| panic!("{}", error.with_file(&expected.input).render()));
+
+        assert_eq!(
+            S::part1(&parsed).to_string(),
+            expected.part1,
+            "Day {} part 1 on {}",
+            S::DAY,
+            expected.input
+        );
+        assert_eq!(
+            S::part2(&parsed).to_string(),
+            expected.part2,
+            "Day {} part 2 on {}",
+            S::DAY,
+            expected.input
+        );
+    }
+}
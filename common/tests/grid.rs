@@ -0,0 +1,140 @@
+use common::grid::{Cell, Grid, Neighbourhood};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+}
+
+impl Cell for Tile {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+        }
+    }
+}
+
+fn sorted_neighbours(
+    grid: &Grid<char>,
+    row: usize,
+    col: usize,
+    neighbourhood: Neighbourhood,
+) -> Vec<(usize, usize)> {
+    let mut neighbours: Vec<(usize, usize)> = grid.neighbours(row, col, neighbourhood).collect();
+    neighbours.sort_unstable();
+    neighbours
+}
+
+#[test]
+fn neighbours_stay_within_the_grid() {
+    let grid: Grid<char> = Grid::parse("abc\ndef\nghi").unwrap();
+
+    assert_eq!(
+        sorted_neighbours(&grid, 0, 0, Neighbourhood::Four),
+        [(0, 1), (1, 0)]
+    );
+    assert_eq!(
+        sorted_neighbours(&grid, 0, 0, Neighbourhood::Eight),
+        [(0, 1), (1, 0), (1, 1)]
+    );
+    assert_eq!(
+        sorted_neighbours(&grid, 2, 2, Neighbourhood::Four),
+        [(1, 2), (2, 1)]
+    );
+    assert_eq!(
+        sorted_neighbours(&grid, 2, 2, Neighbourhood::Eight),
+        [(1, 1), (1, 2), (2, 1)]
+    );
+
+    // Edges, but not corners
+    assert_eq!(
+        sorted_neighbours(&grid, 0, 1, Neighbourhood::Four),
+        [(0, 0), (0, 2), (1, 1)]
+    );
+    assert_eq!(
+        sorted_neighbours(&grid, 1, 2, Neighbourhood::Eight),
+        [(0, 1), (0, 2), (1, 1), (2, 1), (2, 2)]
+    );
+
+    assert_eq!(grid.neighbours(1, 1, Neighbourhood::Four).count(), 4);
+    assert_eq!(grid.neighbours(1, 1, Neighbourhood::Eight).count(), 8);
+}
+
+#[test]
+fn single_cell_has_no_neighbours() {
+    let grid: Grid<char> = Grid::parse("x").unwrap();
+
+    assert_eq!(grid.neighbours(0, 0, Neighbourhood::Eight).count(), 0);
+}
+
+#[test]
+fn ragged_row_is_an_error() {
+    let error = Grid::<char>::parse("abc\nabc\nab\nabc").unwrap_err();
+
+    assert_eq!(error.line, 3);
+    assert_eq!(error.column, 1);
+    assert_eq!(error.text, "ab");
+    assert_eq!(error.message, "Expected a row of width 3, found 2");
+}
+
+#[test]
+fn unknown_cell_is_an_error() {
+    let error = Grid::<Tile>::parse("..#\n.x.").unwrap_err();
+
+    assert_eq!(error.line, 2);
+    assert_eq!(error.column, 2);
+    assert_eq!(error.text, "x");
+    assert_eq!(error.message, "Unknown cell");
+}
+
+#[test]
+fn empty_grid_is_an_error() {
+    assert!(Grid::<Tile>::parse("").is_err());
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid: Grid<char> = Grid::parse("abc\ndef").unwrap();
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    assert_eq!(grid.column(2).collect::<String>(), "cf");
+    assert_eq!(grid[(1, 0)], 'd');
+    assert_eq!(grid.get(2, 0), None);
+}
+
+#[test]
+#[should_panic(expected = "Row 2 out of bounds")]
+fn row_out_of_bounds_panics() {
+    let grid: Grid<char> = Grid::parse("abc\ndef").unwrap();
+
+    grid.row(2);
+}
+
+#[test]
+#[should_panic(expected = "Column 3 out of bounds")]
+fn column_out_of_bounds_panics() {
+    let grid: Grid<char> = Grid::parse("abc\ndef").unwrap();
+
+    let _ = grid.column(3);
+}
+
+#[test]
+fn render_round_trips() {
+    let text = "..#.\n#..#\n....";
+    let grid: Grid<Tile> = Grid::parse(text).unwrap();
+
+    assert_eq!(grid[(0, 2)], Tile::Wall);
+    assert_eq!(grid.render(), text);
+    assert_eq!(Grid::<Tile>::parse(&grid.to_string()).unwrap(), grid);
+}
@@ -0,0 +1,222 @@
+use std::fmt;
+
+/// Number of positions on the puzzle's dial
+pub const DEFAULT_SIZE: u32 = 100;
+/// Position the puzzle's dial points at before the first instruction
+pub const DEFAULT_START: u32 = 50;
+/// Largest dial that gets a lookup table of its targets, answering in constant time.
+/// Larger dials binary search their targets instead of growing the table with their size.
+pub const PREFIX_TABLE_LIMIT: u32 = 1 << 16;
+
+/// Which way an instruction turns the dial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Clockwise, towards higher numbers: `R`, `CW` or `+`
+    Right,
+    /// Counter-clockwise, towards lower numbers: `L`, `CCW` or `-`
+    Left,
+}
+
+impl Direction {
+    /// Splits the direction off the front of an instruction such as `R35`, `CCW35` or
+    /// `-35`, returning the rest of it
+    pub fn split(instruction: &str) -> Option<(Direction, &str)> {
+        const PREFIXES: [(&str, Direction); 6] = [
+            ("CCW", Direction::Left),
+            ("CW", Direction::Right),
+            ("R", Direction::Right),
+            ("L", Direction::Left),
+            ("+", Direction::Right),
+            ("-", Direction::Left),
+        ];
+
+        PREFIXES.iter().find_map(|&(prefix, direction)| {
+            instruction
+                .strip_prefix(prefix)
+                .map(|rest| (direction, rest))
+        })
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Right => f.write_str("R"),
+            Direction::Left => f.write_str("L"),
+        }
+    }
+}
+
+/// A dial with positions `0..size` and a set of target positions that count
+/// towards the passcode whenever the dial points at them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dial {
+    size: u32,
+    start: u32,
+    /// Target positions in ascending order, without duplicates
+    targets: Vec<u32>,
+    /// `prefix_targets[i]` is the number of targets below position `i`, only for dials
+    /// of at most [`PREFIX_TABLE_LIMIT`] positions
+    prefix_targets: Option<Vec<u32>>,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(DEFAULT_SIZE, DEFAULT_START, &[0]).unwrap()
+    }
+}
+
+impl Dial {
+    pub fn new(size: u32, start: u32, targets: &[u32]) -> Result<Self, String> {
+        if size == 0 {
+            return Err(String::from("The dial needs at least one position"));
+        }
+
+        if start >= size {
+            return Err(format!("Start {} is not on a dial of size {}", start, size));
+        }
+
+        if let Some(target) = targets.iter().find(|&&target| target >= size) {
+            return Err(format!(
+                "Target {} is not on a dial of size {}",
+                target, size
+            ));
+        }
+
+        let mut targets = targets.to_vec();
+        targets.sort_unstable();
+        targets.dedup();
+
+        let prefix_targets = (size <= PREFIX_TABLE_LIMIT).then(|| {
+            let mut prefix_targets: Vec<u32> = vec![0; size as usize + 1];
+            for &target in &targets {
+                prefix_targets[target as usize + 1] = 1;
+            }
+            for position in 1..prefix_targets.len() {
+                prefix_targets[position] += prefix_targets[position - 1];
+            }
+
+            prefix_targets
+        });
+
+        Ok(Dial {
+            size,
+            start,
+            targets,
+            prefix_targets,
+        })
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Target positions in ascending order
+    pub fn targets(&self) -> &[u32] {
+        &self.targets
+    }
+
+    /// Whether the position is a target, in O(1) on dials of at most
+    /// [`PREFIX_TABLE_LIMIT`] positions and O(log t) for t targets on larger ones
+    pub fn is_target(&self, position: u32) -> bool {
+        self.targets_between(position, position + 1) > 0
+    }
+
+    /// Number of clicks left after taking out the full turns of a rotation
+    fn reduce(&self, rotation: u128) -> u32 {
+        (rotation % u128::from(self.size)) as u32
+    }
+
+    /// Turns the dial clockwise, towards higher numbers
+    pub fn rotate_right(&self, current: u32, rotation: u128) -> u32 {
+        let clicks = self.reduce(rotation);
+
+        if clicks < self.size - current {
+            current + clicks
+        } else {
+            clicks - (self.size - current)
+        }
+    }
+
+    /// Turns the dial counter-clockwise, towards lower numbers
+    pub fn rotate_left(&self, current: u32, rotation: u128) -> u32 {
+        let clicks = self.reduce(rotation);
+
+        if clicks <= current {
+            current - clicks
+        } else {
+            self.size - (clicks - current)
+        }
+    }
+
+    pub fn rotate(&self, current: u32, rotation: u128, direction: Direction) -> u32 {
+        match direction {
+            Direction::Right => self.rotate_right(current, rotation),
+            Direction::Left => self.rotate_left(current, rotation),
+        }
+    }
+
+    /// Number of targets in `from..to`, where `from <= to <= size`
+    fn targets_between(&self, from: u32, to: u32) -> u32 {
+        match &self.prefix_targets {
+            Some(prefix_targets) => prefix_targets[to as usize] - prefix_targets[from as usize],
+            None => {
+                let below =
+                    |position: u32| self.targets.partition_point(|&target| target < position);
+
+                (below(to) - below(from)) as u32
+            }
+        }
+    }
+
+    /// Returns (number_of_target_hits_during_rotation, new_current), counting every
+    /// click of the rotation that points at a target, including the final one.
+    ///
+    /// Never overflows: every full turn hits each target once and there are no more
+    /// targets than positions, so the hits never exceed the rotation itself.
+    ///
+    /// Takes constant time on dials of at most [`PREFIX_TABLE_LIMIT`] positions. Larger
+    /// dials take O(log t) for t targets, so that memory use does not grow with the size
+    /// of the dial.
+    pub fn count_hits_and_advance(
+        &self,
+        current: u32,
+        rotation: u128,
+        direction: Direction,
+    ) -> (u128, u32) {
+        let full_turns = rotation / u128::from(self.size);
+        let remainder = self.reduce(rotation);
+
+        // The remaining clicks point at the `remainder` positions after `current`
+        let partial_hits = match direction {
+            Direction::Right => {
+                let first = current + 1;
+                if remainder <= self.size - first {
+                    self.targets_between(first, first + remainder)
+                } else {
+                    self.targets_between(first, self.size)
+                        + self.targets_between(0, remainder - (self.size - first))
+                }
+            }
+            Direction::Left => {
+                if remainder <= current {
+                    self.targets_between(current - remainder, current)
+                } else {
+                    self.targets_between(0, current)
+                        + self.targets_between(self.size - (remainder - current), self.size)
+                }
+            }
+        };
+
+        let new_current = self.rotate(current, rotation, direction);
+
+        (
+            full_turns * self.targets.len() as u128 + u128::from(partial_hits),
+            new_current,
+        )
+    }
+}
@@ -0,0 +1,312 @@
+pub mod dial;
+pub mod simulate;
+pub mod stats;
+pub mod stream;
+pub mod synthesize;
+pub mod timeline;
+pub mod trace;
+
+pub use dial::{Dial, Direction};
+
+use common::{ParseError, Solution};
+
+/// A single line of the code file, e.g. `R35`, `CCW12` or `-12`, or `A:R35` to turn the
+/// dial named `A`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instruction {
+    /// 1-based line number in the code file
+    pub line: usize,
+    /// Name of the dial to turn, `None` for the lock's unnamed dial
+    pub dial: Option<String>,
+    pub direction: Direction,
+    pub rotation: u128,
+}
+
+/// Most skipped lines whose error is kept, so that memory use does not grow with the
+/// number of skipped lines
+pub const MAX_SKIPPED_ERRORS: usize = 10;
+
+/// Lines with an unknown direction that lenient parsing skipped
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Skipped {
+    /// Number of skipped lines
+    pub count: usize,
+    /// Errors of the first [`MAX_SKIPPED_ERRORS`] skipped lines
+    pub errors: Vec<ParseError>,
+}
+
+impl Skipped {
+    pub fn push(&mut self, error: ParseError) {
+        self.count += 1;
+
+        if self.errors.len() < MAX_SKIPPED_ERRORS {
+            self.errors.push(error);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+/// The instructions of a code file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Code {
+    pub instructions: Vec<Instruction>,
+    pub skipped: Skipped,
+}
+
+/// Parses a rotation, which unlike `u128::from_str` must not carry a sign of its own
+fn parse_rotation(text: &str) -> Option<u128> {
+    if text.starts_with(|char: char| char.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses a single line of the code file, returning `None` for a blank line. Lines
+/// with an unknown direction are an error in strict mode and are added to `skipped`
+/// otherwise.
+pub fn parse_line(
+    line_number: usize,
+    source_line: &str,
+    strict: bool,
+    skipped: &mut Skipped,
+) -> Result<Option<Instruction>, ParseError> {
+    if source_line.trim().is_empty() {
+        return Ok(None);
+    }
+
+    // Byte offset of the instruction within the line, for pointing at its parts
+    let (dial, offset, line) = match source_line.split_once(':') {
+        Some((name, instruction)) => {
+            if name.trim().is_empty() {
+                return Err(ParseError::at_line(
+                    line_number,
+                    source_line,
+                    "Missing dial name",
+                ));
+            }
+
+            let offset = name.len() + 1 + instruction.len() - instruction.trim_start().len();
+            (Some(String::from(name.trim())), offset, instruction.trim())
+        }
+        None => {
+            let offset = source_line.len() - source_line.trim_start().len();
+            (None, offset, source_line.trim())
+        }
+    };
+
+    if line.is_empty() {
+        return Err(ParseError::at_line(
+            line_number,
+            source_line,
+            "Missing instruction",
+        ));
+    }
+
+    let Some((direction, rotation_text)) = Direction::split(line) else {
+        let error = ParseError::at(
+            line_number,
+            source_line,
+            offset,
+            line.len(),
+            "Unknown direction",
+        );
+        if strict {
+            return Err(error);
+        }
+
+        skipped.push(error);
+        return Ok(None);
+    };
+
+    let rotation = parse_rotation(rotation_text).ok_or_else(|| {
+        ParseError::at(
+            line_number,
+            source_line,
+            offset + line.len() - rotation_text.len(),
+            rotation_text.len(),
+            "Invalid rotation number",
+        )
+    })?;
+
+    Ok(Some(Instruction {
+        line: line_number,
+        dial,
+        direction,
+        rotation,
+    }))
+}
+
+/// Parses one instruction per line, skipping blank lines. A `NAME:` prefix addresses
+/// one of several named dials. Lines with an unknown direction are an error in strict
+/// mode and are skipped otherwise.
+pub fn parse_code(rotation_instructions: &str, strict: bool) -> Result<Code, ParseError> {
+    let mut instructions: Vec<Instruction> = vec![];
+    let mut skipped = Skipped::default();
+
+    for (index, source_line) in rotation_instructions.lines().enumerate() {
+        if let Some(instruction) = parse_line(index + 1, source_line, strict, &mut skipped)? {
+            instructions.push(instruction);
+        }
+    }
+
+    Ok(Code {
+        instructions,
+        skipped,
+    })
+}
+
+/// Parses the code file leniently, dropping lines with an unknown direction
+pub fn parse_instructions(rotation_instructions: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_code(rotation_instructions, false).map(|code| code.instructions)
+}
+
+/// Counts how often the dials of a lock point at one of their targets, either only
+/// after each instruction or also every time they pass one during a rotation. Every
+/// named dial has the geometry of `dial` and keeps its own position; the result is
+/// the sum over all dials.
+///
+/// Panics if the count does not fit in a `u128`, which takes rotations summing to more
+/// than `u128::MAX`.
+pub fn decode(instructions: &[Instruction], dial: &Dial, include_rotation_hits: bool) -> u128 {
+    total(&decode_dials(instructions, dial, include_rotation_hits))
+}
+
+/// Sums the counts of all dials into the passcode. Panics if it does not fit in a
+/// `u128`.
+pub fn total(counts: &[DialCount]) -> u128 {
+    counts
+        .iter()
+        .try_fold(0u128, |total, dial_count| {
+            total.checked_add(dial_count.count)
+        })
+        .expect("Passcode does not fit in a u128")
+}
+
+/// How often one dial of a lock pointed at a target
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialCount {
+    /// Name of the dial, `None` for the lock's unnamed dial
+    pub name: Option<String>,
+    pub count: u128,
+}
+
+/// Counts like [`decode`] but separately for every dial, in order of first appearance
+pub fn decode_dials(
+    instructions: &[Instruction],
+    dial: &Dial,
+    include_rotation_hits: bool,
+) -> Vec<DialCount> {
+    let mut lock = Lock::new(dial);
+    let mut counts: Vec<DialCount> = vec![];
+
+    for instruction in instructions {
+        let (step_hits, after) = lock.turn(instruction);
+        let index = lock.index(instruction.dial.as_deref());
+
+        if index == counts.len() {
+            counts.push(DialCount {
+                name: instruction.dial.clone(),
+                count: 0,
+            });
+        }
+
+        let hits = if include_rotation_hits {
+            step_hits
+        } else {
+            u128::from(dial.is_target(after))
+        };
+
+        counts[index].count = counts[index]
+            .count
+            .checked_add(hits)
+            .expect("Passcode does not fit in a u128");
+    }
+
+    counts
+}
+
+/// Positions of the dials of a lock, in order of first appearance
+pub(crate) struct Lock<'a> {
+    dial: &'a Dial,
+    names: Vec<Option<String>>,
+    positions: Vec<u32>,
+}
+
+impl<'a> Lock<'a> {
+    pub(crate) fn new(dial: &'a Dial) -> Self {
+        Lock {
+            dial,
+            names: vec![],
+            positions: vec![],
+        }
+    }
+
+    /// Index of the named dial, adding it at the dial's start if it is new
+    pub(crate) fn index(&mut self, name: Option<&str>) -> usize {
+        match self.names.iter().position(|known| known.as_deref() == name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.map(String::from));
+                self.positions.push(self.dial.start());
+                self.names.len() - 1
+            }
+        }
+    }
+
+    /// Current position of the named dial, adding it at the dial's start if it is new
+    pub(crate) fn position(&mut self, name: Option<&str>) -> u32 {
+        let index = self.index(name);
+        self.positions[index]
+    }
+
+    /// Turns the instruction's dial, returning how often it pointed at a target during
+    /// the rotation, including where it stopped, and its new position
+    pub(crate) fn turn(&mut self, instruction: &Instruction) -> (u128, u32) {
+        self.turn_with(instruction, Dial::count_hits_and_advance)
+    }
+
+    /// Like [`Lock::turn`], with `advance` in place of [`Dial::count_hits_and_advance`]
+    pub(crate) fn turn_with(
+        &mut self,
+        instruction: &Instruction,
+        advance: impl Fn(&Dial, u32, u128, Direction) -> (u128, u32),
+    ) -> (u128, u32) {
+        let index = self.index(instruction.dial.as_deref());
+        let (hits, after) = advance(
+            self.dial,
+            self.positions[index],
+            instruction.rotation,
+            instruction.direction,
+        );
+        self.positions[index] = after;
+
+        (hits, after)
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = "code.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Instruction>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Self::Answer {
+        decode(instructions, &Dial::default(), false)
+    }
+
+    fn part2(instructions: &Self::Input) -> Self::Answer {
+        decode(instructions, &Dial::default(), true)
+    }
+}
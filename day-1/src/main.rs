@@ -1,85 +1,376 @@
-use std::fs;
+use common::cli::{parse_options, parse_value};
+use common::input::{load_input_with, resolve_source};
+use day_1::dial::{DEFAULT_SIZE, DEFAULT_START};
+use day_1::stats::{stats, to_report};
+use day_1::stream::{StreamError, decode_stream};
+use day_1::synthesize::synthesize;
+use day_1::timeline::Timeline;
+use day_1::trace::{to_csv, to_json, trace};
+use day_1::{Code, Day1, Dial, DialCount, Skipped, decode, parse_code, total};
+use std::env;
+use std::process;
 
-const MOD: u32 = 100;
+const USAGE: &str = "Usage:
+    day-1 [OPTIONS] [INPUT]
+    day-1 trace [--format csv|json] [OPTIONS] [INPUT]
+    day-1 stats [OPTIONS] [INPUT]
+    day-1 query [--dial NAME] [--position STEP]... [--hits FROM..TO]... [OPTIONS] [INPUT]
+    day-1 synthesize --passcode N [--part 1|2] [--max-length N] [OPTIONS]
 
-fn rotate_right(current: u32, rotation: u32) -> u32 {
-    (current + rotation) % MOD
+Options:
+    --strict            Reject lines with an unknown direction instead of skipping them
+    --size N            Number of positions on the dial (default 100). Dials above 65536
+                        positions take O(log targets) per instruction instead of O(1)
+    --start N           Position before the first instruction (default 50)
+    --targets A,B,...   Positions that count towards the passcode (default 0)
+
+Queries count the hits of all dials together, unless --dial names one the code turns.";
+
+/// Longest code `synthesize` writes unless told otherwise
+const DEFAULT_MAX_LENGTH: usize = 1_000_000;
+
+/// A question about the state of the lock after some of the instructions
+enum Query {
+    Position(usize),
+    Hits(usize, usize),
 }
 
-fn rotate_left(current: u32, rotation: u32) -> u32 {
-    (current + MOD - (rotation % MOD)) % MOD
+enum Command {
+    Decode,
+    Trace {
+        json: bool,
+    },
+    Stats,
+    Query {
+        dial: Option<String>,
+        queries: Vec<Query>,
+    },
+    Synthesize {
+        passcode: Option<u128>,
+        include_rotation_hits: bool,
+        max_length: usize,
+    },
 }
 
-/// Returns (number_of_zero_hits_during_rotation, new_current)
-fn count_zero_hits_and_advance(current: u32, rotation: u32, direction: char) -> (u32, u32) {
-    // first positive rotation in [1..100]
-    let mut first_rotation_result = match direction {
-        'R' => (MOD - (current % MOD)) % MOD,
-        'L' => current % MOD,
-        _ => return (0, current),
-    };
+struct Args<'a> {
+    command: Command,
+    dial: Dial,
+    strict: bool,
+    path: Option<&'a str>,
+}
 
-    first_rotation_result = if first_rotation_result == 0 {
-        MOD
-    } else {
-        first_rotation_result
-    }; // 0 means 100th click
-    let zeros = if first_rotation_result > rotation {
-        0
-    } else {
-        1 + (rotation - first_rotation_result) / MOD
+fn parse_args(args: &[String]) -> Result<Args<'_>, String> {
+    let (mut command, options) = match args.split_first() {
+        Some((command, options)) if command == "trace" => (Command::Trace { json: false }, options),
+        Some((command, options)) if command == "stats" => (Command::Stats, options),
+        Some((command, options)) if command == "query" => (
+            Command::Query {
+                dial: None,
+                queries: vec![],
+            },
+            options,
+        ),
+        Some((command, options)) if command == "synthesize" => (
+            Command::Synthesize {
+                passcode: None,
+                include_rotation_hits: false,
+                max_length: DEFAULT_MAX_LENGTH,
+            },
+            options,
+        ),
+        _ => (Command::Decode, args),
     };
 
-    let new_current = match direction {
-        'R' => rotate_right(current, rotation),
-        'L' => rotate_left(current, rotation),
-        _ => current,
-    };
+    let mut path: Option<&str> = None;
+    let mut size = DEFAULT_SIZE;
+    let mut start = DEFAULT_START;
+    let mut targets = vec![0];
+    let mut strict = false;
+
+    for (option, value) in parse_options(
+        options,
+        &[
+            "--format",
+            "--dial",
+            "--position",
+            "--hits",
+            "--passcode",
+            "--part",
+            "--max-length",
+            "--size",
+            "--start",
+            "--targets",
+        ],
+    )? {
+        match (option, value, &mut command) {
+            ("--format", Some(format), Command::Trace { json }) => match format {
+                "csv" => *json = false,
+                "json" => *json = true,
+                _ => return Err(format!("Unknown format '{}'", format)),
+            },
+            ("--dial", Some(name), Command::Query { dial, .. }) => *dial = Some(String::from(name)),
+            ("--position", Some(step), Command::Query { queries, .. }) => {
+                queries.push(Query::Position(parse_value(option, step)?))
+            }
+            ("--hits", Some(steps), Command::Query { queries, .. }) => {
+                let (from, to) = steps.split_once("..").ok_or_else(|| {
+                    format!("Expected FROM..TO for {}, found '{}'", option, steps)
+                })?;
+                queries.push(Query::Hits(
+                    parse_value(option, from)?,
+                    parse_value(option, to)?,
+                ))
+            }
+            ("--passcode", Some(value), Command::Synthesize { passcode, .. }) => {
+                *passcode = Some(parse_value(option, value)?)
+            }
+            (
+                "--part",
+                Some(part),
+                Command::Synthesize {
+                    include_rotation_hits,
+                    ..
+                },
+            ) => match part {
+                "1" => *include_rotation_hits = false,
+                "2" => *include_rotation_hits = true,
+                _ => return Err(format!("Unknown part '{}'", part)),
+            },
+            ("--max-length", Some(value), Command::Synthesize { max_length, .. }) => {
+                *max_length = parse_value(option, value)?
+            }
+            ("--strict", None, _) => strict = true,
+            ("--size", Some(value), _) => size = parse_value(option, value)?,
+            ("--start", Some(value), _) => start = parse_value(option, value)?,
+            ("--targets", Some(value), _) => {
+                targets = value
+                    .split(',')
+                    .map(|target| parse_value(option, target.trim()))
+                    .collect::<Result<_, _>>()?
+            }
+            (arg, None, _) if path.is_none() && (arg == "-" || !arg.starts_with("--")) => {
+                path = Some(arg)
+            }
+            _ => return Err(format!("Unexpected argument '{}'", option)),
+        }
+    }
 
-    (zeros, new_current)
+    match &command {
+        Command::Query { queries, .. } if queries.is_empty() => {
+            return Err(String::from("Nothing to query"));
+        }
+        Command::Synthesize { passcode: None, .. } => {
+            return Err(String::from("Missing --passcode"));
+        }
+        Command::Synthesize { .. } if path.is_some() => {
+            return Err(String::from("synthesize reads no input"));
+        }
+        _ => {}
+    }
+
+    Ok(Args {
+        command,
+        dial: Dial::new(size, start, &targets)?,
+        strict,
+        path,
+    })
 }
-fn decode_file(include_rotation_hits: bool) -> u32 {
-    let mut current_value: u32 = 50;
-    let mut zero_count: u32 = 0;
 
-    let rotation_instructions =
-        fs::read_to_string("day-1/code.txt").expect("Code file needs to be present!");
+/// Prints the combined passcode, followed by the count of every dial if the lock
+/// has named dials
+fn print_passcode(number: u8, counts: &[DialCount]) {
+    println!("Passcode {} is: {}", number, total(counts));
 
-    for line in rotation_instructions.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+    if counts.iter().any(|dial_count| dial_count.name.is_some()) {
+        for dial_count in counts {
+            let name = dial_count.name.as_deref().unwrap_or("(unnamed)");
+            println!("    Dial {}: {}", name, dial_count.count);
         }
+    }
+}
 
-        let mut chars = line.chars();
-        let direction = chars.next().unwrap();
-        let rotation_count: u32 = chars.as_str().parse().expect("Invalid rotation number");
-
-        if include_rotation_hits {
-            let (hits, new_current) =
-                count_zero_hits_and_advance(current_value, rotation_count, direction);
-            zero_count += hits;
-            current_value = new_current;
+fn answer_query(timeline: &Timeline, dial: Option<&str>, query: &Query) -> Result<(), String> {
+    let check_step = |step: usize| {
+        if step <= timeline.len() {
+            Ok(step)
         } else {
-            match direction {
-                'R' => current_value = rotate_right(current_value, rotation_count),
-                'L' => current_value = rotate_left(current_value, rotation_count),
-                _ => continue,
+            Err(format!(
+                "Step {} is past the last step {}",
+                step,
+                timeline.len()
+            ))
+        }
+    };
+    let unknown_dial = || format!("Unknown dial '{}'", dial.unwrap_or_default());
+
+    match *query {
+        Query::Position(step) => {
+            let position = timeline
+                .position_at(dial, check_step(step)?)
+                .ok_or_else(unknown_dial)?;
+
+            match dial {
+                Some(name) => println!("Dial {} at step {}: {}", name, step, position),
+                None => println!("Dial at step {}: {}", step, position),
+            }
+        }
+        Query::Hits(from, to) => {
+            if from > check_step(to)? {
+                return Err(format!("Step {} comes after step {}", from, to));
             }
 
-            if current_value == 0 {
-                zero_count += 1;
+            match dial {
+                Some(name) => println!(
+                    "Dial {} steps {} to {}: {} hits, {} landings",
+                    name,
+                    from,
+                    to,
+                    timeline
+                        .dial_hits_between(dial, from, to)
+                        .ok_or_else(unknown_dial)?,
+                    timeline
+                        .dial_landings_between(dial, from, to)
+                        .ok_or_else(unknown_dial)?
+                ),
+                None => println!(
+                    "Steps {} to {}: {} hits, {} landings",
+                    from,
+                    to,
+                    timeline.hits_between(from, to),
+                    timeline.landings_between(from, to)
+                ),
             }
         }
     }
 
-    zero_count
+    Ok(())
+}
+
+/// Prints a shortest code decoding to the passcode, replaying it to make sure
+fn print_synthesized(dial: &Dial, passcode: u128, include_rotation_hits: bool, max_length: usize) {
+    let Some(instructions) = synthesize(dial, passcode, include_rotation_hits, max_length) else {
+        eprintln!(
+            "No code of at most {} instructions decodes to {}",
+            max_length, passcode
+        );
+        process::exit(1);
+    };
+
+    let decoded = decode(&instructions, dial, include_rotation_hits);
+    if decoded != passcode {
+        eprintln!(
+            "Synthesized code decodes to {} instead of {}",
+            decoded, passcode
+        );
+        process::exit(1);
+    }
+
+    for instruction in &instructions {
+        println!("{}{}", instruction.direction, instruction.rotation);
+    }
+    eprintln!(
+        "Verified: {} instructions decode to {}",
+        instructions.len(),
+        passcode
+    );
+}
+
+fn load_code(path: Option<&str>, strict: bool) -> Code {
+    load_input_with::<Day1, _>(path, |input| parse_code(input, strict))
+}
+
+fn report_skipped(skipped: &Skipped) {
+    if !skipped.is_empty() {
+        eprintln!("Skipped {} lines:", skipped.count);
+
+        for error in &skipped.errors {
+            eprintln!("  {}", error);
+        }
+
+        if skipped.count > skipped.errors.len() {
+            eprintln!("  ... and {} more", skipped.count - skipped.errors.len());
+        }
+    }
+}
+
+/// Prints both passcodes, streaming the code file instead of reading it all at once
+fn print_decoded(path: Option<&str>, dial: &Dial, strict: bool) {
+    let source = resolve_source::<Day1>(path).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    let passcodes = source
+        .open()
+        .map_err(StreamError::from)
+        .and_then(|reader| decode_stream(reader, dial, strict))
+        .unwrap_or_else(|error| {
+            match error {
+                StreamError::Io(error) => eprintln!("Could not read input {}: {}", source, error),
+                StreamError::Parse(error) => {
+                    eprintln!("{}", error.with_file(source.to_string()).render())
+                }
+            }
+            process::exit(1);
+        });
+
+    print_passcode(1, &passcodes.landings);
+    print_passcode(2, &passcodes.hits);
+    report_skipped(&passcodes.skipped);
 }
 
 fn main() {
-    let code_1 = decode_file(false);
-    let code_2 = decode_file(true);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Args {
+        command,
+        dial,
+        strict,
+        path,
+    } = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Synthesize {
+            passcode,
+            include_rotation_hits,
+            max_length,
+        } => {
+            let passcode = passcode.expect("passcode is checked while parsing the arguments");
+            print_synthesized(&dial, passcode, include_rotation_hits, max_length);
+        }
+        Command::Decode => print_decoded(path, &dial, strict),
+        Command::Trace { json } => {
+            let code = load_code(path, strict);
+            let steps = trace(&code.instructions, &dial);
 
-    println!("Passcode 1 is: {}", code_1);
-    println!("Passcode 2 is: {}", code_2);
+            if json {
+                println!("{}", to_json(&steps));
+            } else {
+                print!("{}", to_csv(&steps));
+            }
+            report_skipped(&code.skipped);
+        }
+        Command::Stats => {
+            let code = load_code(path, strict);
+            print!("{}", to_report(&stats(&code.instructions, &dial)));
+            report_skipped(&code.skipped);
+        }
+        Command::Query {
+            dial: name,
+            queries,
+        } => {
+            let code = load_code(path, strict);
+            let timeline = Timeline::new(&code.instructions, &dial);
+
+            for query in queries {
+                if let Err(error) = answer_query(&timeline, name.as_deref(), &query) {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }
+            }
+            report_skipped(&code.skipped);
+        }
+    }
 }
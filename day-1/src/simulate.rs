@@ -0,0 +1,42 @@
+use crate::{Dial, Direction, Instruction, Lock};
+
+/// Turns the dial one click at a time, returning the same (hits, new_current) as
+/// [`Dial::count_hits_and_advance`]. Takes time linear in the rotation, so it is only
+/// meant to check the closed form against.
+pub fn click_by_click(
+    dial: &Dial,
+    current: u32,
+    rotation: u128,
+    direction: Direction,
+) -> (u128, u32) {
+    let mut position = current;
+    let mut hits: u128 = 0;
+
+    for _ in 0..rotation {
+        position = dial.rotate(position, 1, direction);
+
+        if dial.is_target(position) {
+            hits += 1;
+        }
+    }
+
+    (hits, position)
+}
+
+/// Decodes the instructions like [`crate::decode`], clicking through every rotation
+pub fn simulate(instructions: &[Instruction], dial: &Dial, include_rotation_hits: bool) -> u128 {
+    let mut lock = Lock::new(dial);
+    let mut target_count: u128 = 0;
+
+    for instruction in instructions {
+        let (hits, after) = lock.turn_with(instruction, click_by_click);
+
+        target_count += if include_rotation_hits {
+            hits
+        } else {
+            u128::from(dial.is_target(after))
+        };
+    }
+
+    target_count
+}
@@ -0,0 +1,153 @@
+use crate::{Dial, Direction, Instruction, Lock};
+use std::collections::BTreeMap;
+
+/// Width of the longest bar of the landing histogram
+const BAR_WIDTH: usize = 50;
+
+/// Where the dials stopped and how far they travelled over a whole code file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub instructions: usize,
+    /// Number of positions on the dial
+    pub size: u32,
+    /// How often an instruction stopped at each position, leaving out the positions
+    /// never stopped at
+    pub landings: BTreeMap<u32, usize>,
+    /// Clicks turned clockwise, summed over all instructions
+    pub clockwise: u128,
+    /// Clicks turned counter-clockwise, summed over all instructions
+    pub counter_clockwise: u128,
+    /// Lines of the first and last instruction of the longest run of instructions that
+    /// never pointed at a target, if there is any such instruction
+    pub longest_miss: Option<(usize, usize)>,
+    /// Number of instructions in the longest run that never pointed at a target
+    pub longest_miss_length: usize,
+}
+
+impl Stats {
+    /// Positions stopped at most often, in ascending order
+    pub fn most_visited(&self) -> Vec<u32> {
+        let most = self.landings.values().max().copied().unwrap_or(0);
+        self.positions_visited(most)
+    }
+
+    /// Positions stopped at least often but at least once, in ascending order
+    pub fn least_visited(&self) -> Vec<u32> {
+        let least = self.landings.values().min().copied().unwrap_or(0);
+        self.positions_visited(least)
+    }
+
+    /// Number of positions never stopped at
+    pub fn never_visited(&self) -> u32 {
+        self.size - self.landings.len() as u32
+    }
+
+    fn positions_visited(&self, times: usize) -> Vec<u32> {
+        self.landings
+            .iter()
+            .filter(|&(_, &landings)| landings == times)
+            .map(|(&position, _)| position)
+            .collect()
+    }
+}
+
+/// Replays the instructions, collecting statistics over every dial of the lock
+pub fn stats(instructions: &[Instruction], dial: &Dial) -> Stats {
+    let mut lock = Lock::new(dial);
+    let mut stats = Stats {
+        instructions: instructions.len(),
+        size: dial.size(),
+        landings: BTreeMap::new(),
+        clockwise: 0,
+        counter_clockwise: 0,
+        longest_miss: None,
+        longest_miss_length: 0,
+    };
+    let mut miss_start: Option<usize> = None;
+    let mut miss_length = 0;
+
+    for instruction in instructions {
+        let (hits, after) = lock.turn(instruction);
+        *stats.landings.entry(after).or_insert(0) += 1;
+
+        let travel = match instruction.direction {
+            Direction::Right => &mut stats.clockwise,
+            Direction::Left => &mut stats.counter_clockwise,
+        };
+        *travel = travel
+            .checked_add(instruction.rotation)
+            .expect("Travel does not fit in a u128");
+
+        if hits > 0 {
+            miss_start = None;
+            miss_length = 0;
+            continue;
+        }
+
+        let start = *miss_start.get_or_insert(instruction.line);
+        miss_length += 1;
+
+        if miss_length > stats.longest_miss_length {
+            stats.longest_miss = Some((start, instruction.line));
+            stats.longest_miss_length = miss_length;
+        }
+    }
+
+    stats
+}
+
+fn join(positions: &[u32]) -> String {
+    positions
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Renders the statistics as a human readable report with a histogram of the
+/// positions stopped at
+pub fn to_report(stats: &Stats) -> String {
+    let most = stats.landings.values().max().copied().unwrap_or(0);
+    let least = stats.landings.values().min().copied().unwrap_or(0);
+    let longest_miss = match stats.longest_miss {
+        Some((first, last)) => format!(
+            "{} instructions (lines {} to {})",
+            stats.longest_miss_length, first, last
+        ),
+        None => String::from("0 instructions"),
+    };
+
+    let mut report = format!(
+        "Instructions: {}\n\
+         Clockwise travel: {} clicks\n\
+         Counter-clockwise travel: {} clicks\n\
+         Longest run without a target: {}\n\
+         Most visited: {} ({} landings)\n\
+         Least visited: {} ({} landings)\n\
+         Never visited: {} positions\n\
+         Landings per position:\n",
+        stats.instructions,
+        stats.clockwise,
+        stats.counter_clockwise,
+        longest_miss,
+        join(&stats.most_visited()),
+        most,
+        join(&stats.least_visited()),
+        least,
+        stats.never_visited()
+    );
+
+    let position_width = (stats.size - 1).to_string().len();
+    let count_width = most.to_string().len();
+
+    for (position, &landings) in &stats.landings {
+        report.push_str(&format!(
+            "{:>position_width$} {:>count_width$} {}\n",
+            position,
+            landings,
+            "#".repeat((landings * BAR_WIDTH).div_ceil(most))
+        ));
+    }
+
+    report
+}
@@ -0,0 +1,100 @@
+use crate::{Dial, DialCount, Lock, Skipped, parse_line};
+use common::ParseError;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Why a code file could not be decoded while streaming it
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Both passcodes of a code file, counted separately for every dial
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passcodes {
+    /// How often each dial stopped on a target
+    pub landings: Vec<DialCount>,
+    /// How often each dial pointed at a target during any click
+    pub hits: Vec<DialCount>,
+    pub skipped: Skipped,
+}
+
+fn add(counts: &mut [DialCount], index: usize, count: u128) {
+    counts[index].count = counts[index]
+        .count
+        .checked_add(count)
+        .expect("Passcode does not fit in a u128");
+}
+
+/// Decodes the code file line by line in a single pass, computing both passcodes at
+/// once. Memory use does not depend on the number of instructions or skipped lines,
+/// only on the number of dials and the longest line.
+pub fn decode_stream(
+    mut reader: impl BufRead,
+    dial: &Dial,
+    strict: bool,
+) -> Result<Passcodes, StreamError> {
+    let mut lock = Lock::new(dial);
+    let mut landings: Vec<DialCount> = vec![];
+    let mut hits: Vec<DialCount> = vec![];
+    let mut skipped = Skipped::default();
+    let mut source_line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        source_line.clear();
+        if reader.read_line(&mut source_line)? == 0 {
+            break;
+        }
+        line_number += 1;
+
+        let source_line = source_line.trim_end_matches(['\n', '\r']);
+        let Some(instruction) = parse_line(line_number, source_line, strict, &mut skipped)? else {
+            continue;
+        };
+
+        let (step_hits, after) = lock.turn(&instruction);
+        let index = lock.index(instruction.dial.as_deref());
+        if index == hits.len() {
+            for counts in [&mut landings, &mut hits] {
+                counts.push(DialCount {
+                    name: instruction.dial.clone(),
+                    count: 0,
+                });
+            }
+        }
+
+        add(&mut landings, index, u128::from(dial.is_target(after)));
+        add(&mut hits, index, step_hits);
+    }
+
+    Ok(Passcodes {
+        landings,
+        hits,
+        skipped,
+    })
+}
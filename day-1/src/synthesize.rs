@@ -0,0 +1,97 @@
+use crate::{Dial, Direction, Instruction};
+
+/// Clicks clockwise from `current` until the dial points at `target`, between 1 and
+/// the size of the dial
+fn distance_right(dial: &Dial, current: u32, target: u32) -> u32 {
+    let size = u64::from(dial.size());
+
+    ((u64::from(target) + size - u64::from(current) - 1) % size + 1) as u32
+}
+
+/// `a * b + c` as its high and low 128 bits
+fn widening_mul_add(a: u128, b: u32, c: u32) -> (u128, u128) {
+    let b = u128::from(b);
+    let low = (a & u128::from(u64::MAX)) * b + u128::from(c);
+    let middle = (a >> 64) * b;
+    let (low, carry) = (middle << 64).overflowing_add(low);
+
+    ((middle >> 64) + u128::from(carry), low)
+}
+
+fn turn_right(line: usize, rotation: u128) -> Instruction {
+    Instruction {
+        line,
+        dial: None,
+        direction: Direction::Right,
+        rotation,
+    }
+}
+
+/// Builds a shortest code that [`crate::decode`] turns into `passcode` on `dial`, or
+/// `None` if there is none of at most `max_length` instructions.
+///
+/// Counting every click, a single rotation to the `passcode`-th target click does, split
+/// into as few rotations as fit in a `u128` when it does not.
+/// Counting only where the dial stops, every instruction lands on a target: the first
+/// one on the nearest target, the rest by turning a full circle.
+pub fn synthesize(
+    dial: &Dial,
+    passcode: u128,
+    include_rotation_hits: bool,
+    max_length: usize,
+) -> Option<Vec<Instruction>> {
+    if passcode == 0 {
+        return Some(vec![]);
+    }
+
+    let start = dial.start();
+    let mut distances: Vec<u32> = dial
+        .targets()
+        .iter()
+        .map(|&target| distance_right(dial, start, target))
+        .collect();
+    distances.sort_unstable();
+
+    let nearest = *distances.first()?;
+
+    if include_rotation_hits {
+        let targets = distances.len() as u128;
+        let full_turns = (passcode - 1) / targets;
+        let last = distances[((passcode - 1) % targets) as usize];
+        let (mut high, mut low) = widening_mul_add(full_turns, dial.size(), last);
+
+        // Each rotation of u128::MAX clicks takes 2^128 - 1 off the wide total
+        let mut instructions = vec![];
+        while high > 0 || low > 0 {
+            if instructions.len() == max_length {
+                return None;
+            }
+
+            if high > 0 {
+                instructions.push(turn_right(instructions.len() + 1, u128::MAX));
+                let (rest, carry) = low.overflowing_add(1);
+                high = high - 1 + u128::from(carry);
+                low = rest;
+            } else {
+                instructions.push(turn_right(instructions.len() + 1, low));
+                low = 0;
+            }
+        }
+
+        Some(instructions)
+    } else {
+        let length = usize::try_from(passcode).ok()?;
+        if length > max_length {
+            return None;
+        }
+
+        Some(
+            (1..=length)
+                .map(|line| match line {
+                    1 => turn_right(line, u128::from(nearest)),
+                    _ => turn_right(line, u128::from(dial.size())),
+                })
+                .collect(),
+        )
+    }
+}
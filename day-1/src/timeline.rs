@@ -0,0 +1,178 @@
+use crate::{Dial, Instruction, Lock};
+
+/// Where a single dial of the lock was after each instruction turning it
+struct DialHistory {
+    name: Option<String>,
+    /// Steps that turned the dial, in ascending order
+    steps: Vec<usize>,
+    /// Position of the dial after each of `steps`
+    positions: Vec<u32>,
+    /// `hits[i]` is the number of clicks pointing at a target during the first `i` turns
+    hits: Vec<u128>,
+    /// `landings[i]` is the number of the first `i` turns stopping on a target
+    landings: Vec<u128>,
+}
+
+impl DialHistory {
+    /// Number of turns up to and including the given step
+    fn turns_until(&self, step: usize) -> usize {
+        self.steps.partition_point(|&turned| turned <= step)
+    }
+}
+
+/// The state of the lock after every instruction, preprocessed for queries. Step `i`
+/// is the state after the first `i` instructions, so step 0 is before any of them.
+pub struct Timeline {
+    start: u32,
+    dials: Vec<DialHistory>,
+    /// `hits[i]` is the number of clicks pointing at a target during the first `i` steps
+    hits: Vec<u128>,
+    /// `landings[i]` is the number of the first `i` steps stopping on a target
+    landings: Vec<u128>,
+}
+
+impl Timeline {
+    pub fn new(instructions: &[Instruction], dial: &Dial) -> Self {
+        let mut lock = Lock::new(dial);
+        let mut dials: Vec<DialHistory> = vec![];
+        let mut hits: Vec<u128> = Vec::with_capacity(instructions.len() + 1);
+        let mut landings: Vec<u128> = Vec::with_capacity(instructions.len() + 1);
+        hits.push(0);
+        landings.push(0);
+
+        for (step, instruction) in (1..).zip(instructions) {
+            let (step_hits, after) = lock.turn(instruction);
+            let index = lock.index(instruction.dial.as_deref());
+            if index == dials.len() {
+                dials.push(DialHistory {
+                    name: instruction.dial.clone(),
+                    steps: vec![],
+                    positions: vec![],
+                    hits: vec![0],
+                    landings: vec![0],
+                });
+            }
+
+            let landed = u128::from(dial.is_target(after));
+
+            hits.push(
+                hits[step - 1]
+                    .checked_add(step_hits)
+                    .expect("Passcode does not fit in a u128"),
+            );
+            landings.push(landings[step - 1] + landed);
+
+            // A single dial never counts more than all of them together
+            let history = &mut dials[index];
+            history.steps.push(step);
+            history.positions.push(after);
+            history.hits.push(history.hits.last().unwrap() + step_hits);
+            history
+                .landings
+                .push(history.landings.last().unwrap() + landed);
+        }
+
+        Timeline {
+            start: dial.start(),
+            dials,
+            hits,
+            landings,
+        }
+    }
+
+    /// Number of instructions, which is also the last step
+    pub fn len(&self) -> usize {
+        self.hits.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the lock has the named dial: the unnamed one always exists, named ones
+    /// only if the code turns them
+    pub fn has_dial(&self, dial: Option<&str>) -> bool {
+        dial.is_none() || self.history(dial).is_some()
+    }
+
+    /// Position of the named dial (`None` for the unnamed one) at the given step, in
+    /// O(log n), or `None` if the lock has no such dial. Panics if the step is past
+    /// the last one.
+    pub fn position_at(&self, dial: Option<&str>, step: usize) -> Option<u32> {
+        assert!(step <= self.len(), "Step {} out of bounds", step);
+
+        if !self.has_dial(dial) {
+            return None;
+        }
+
+        Some(
+            self.history(dial)
+                .map_or(self.start, |history| match history.turns_until(step) {
+                    0 => self.start,
+                    turns => history.positions[turns - 1],
+                }),
+        )
+    }
+
+    fn history(&self, dial: Option<&str>) -> Option<&DialHistory> {
+        self.dials
+            .iter()
+            .find(|history| history.name.as_deref() == dial)
+    }
+
+    /// Difference of one of the named dial's prefix sums between steps `from` and `to`,
+    /// in O(log n), or `None` if the lock has no such dial
+    fn dial_between(
+        &self,
+        dial: Option<&str>,
+        from: usize,
+        to: usize,
+        prefix: impl Fn(&DialHistory) -> &[u128],
+    ) -> Option<u128> {
+        assert!(from <= to, "Step {} comes after step {}", from, to);
+        assert!(to <= self.len(), "Step {} out of bounds", to);
+
+        if !self.has_dial(dial) {
+            return None;
+        }
+
+        Some(self.history(dial).map_or(0, |history| {
+            let prefix = prefix(history);
+            prefix[history.turns_until(to)] - prefix[history.turns_until(from)]
+        }))
+    }
+
+    /// Like [`Timeline::hits_between`], but only for the named dial (`None` for the
+    /// unnamed one), in O(log n), or `None` if the lock has no such dial
+    pub fn dial_hits_between(&self, dial: Option<&str>, from: usize, to: usize) -> Option<u128> {
+        self.dial_between(dial, from, to, |history| &history.hits)
+    }
+
+    /// Like [`Timeline::landings_between`], but only for the named dial (`None` for the
+    /// unnamed one), in O(log n), or `None` if the lock has no such dial
+    pub fn dial_landings_between(
+        &self,
+        dial: Option<&str>,
+        from: usize,
+        to: usize,
+    ) -> Option<u128> {
+        self.dial_between(dial, from, to, |history| &history.landings)
+    }
+
+    /// Number of clicks pointing at a target on any dial between steps `from` and `to`,
+    /// i.e. during instructions `from + 1..=to`, in O(1). Panics unless
+    /// `from <= to <= len`.
+    pub fn hits_between(&self, from: usize, to: usize) -> u128 {
+        assert!(from <= to, "Step {} comes after step {}", from, to);
+
+        self.hits[to] - self.hits[from]
+    }
+
+    /// Number of instructions `from + 1..=to` stopping on a target on any dial, in O(1).
+    /// Panics unless `from <= to <= len`.
+    pub fn landings_between(&self, from: usize, to: usize) -> u128 {
+        assert!(from <= to, "Step {} comes after step {}", from, to);
+
+        self.landings[to] - self.landings[from]
+    }
+}
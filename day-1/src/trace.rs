@@ -0,0 +1,80 @@
+use crate::{Dial, Direction, Instruction, Lock};
+use common::csv;
+use common::json::{Json, render_lines};
+
+/// What a single instruction did to the dial
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceStep {
+    pub line: usize,
+    /// Name of the dial turned, `None` for the lock's unnamed dial
+    pub dial: Option<String>,
+    pub direction: Direction,
+    pub rotation: u128,
+    pub before: u32,
+    pub after: u32,
+    /// How often the dial pointed at a target during the rotation, including where it
+    /// stopped
+    pub hits: u128,
+}
+
+/// Replays the instructions, recording every step of the lock's dials
+pub fn trace(instructions: &[Instruction], dial: &Dial) -> Vec<TraceStep> {
+    let mut lock = Lock::new(dial);
+
+    instructions
+        .iter()
+        .map(|instruction| {
+            let before = lock.position(instruction.dial.as_deref());
+            let (hits, after) = lock.turn(instruction);
+
+            TraceStep {
+                line: instruction.line,
+                dial: instruction.dial.clone(),
+                direction: instruction.direction,
+                rotation: instruction.rotation,
+                before,
+                after,
+                hits,
+            }
+        })
+        .collect()
+}
+
+pub fn to_csv(steps: &[TraceStep]) -> String {
+    let mut output = String::from("line,dial,direction,rotation,before,after,hits\n");
+
+    for step in steps {
+        output.push_str(&csv::row(&[
+            step.line.to_string(),
+            step.dial.clone().unwrap_or_default(),
+            step.direction.to_string(),
+            step.rotation.to_string(),
+            step.before.to_string(),
+            step.after.to_string(),
+            step.hits.to_string(),
+        ]));
+    }
+
+    output
+}
+
+/// One record per step. Rotations and hits are strings so that they keep their
+/// precision, like the answers of `aoc run --format json`.
+pub fn to_json(steps: &[TraceStep]) -> String {
+    let records: Vec<Json> = steps
+        .iter()
+        .map(|step| {
+            Json::object([
+                ("line", Json::from(step.line)),
+                ("dial", Json::from(step.dial.clone())),
+                ("direction", Json::from(step.direction.to_string())),
+                ("rotation", Json::from(step.rotation.to_string())),
+                ("before", Json::from(step.before)),
+                ("after", Json::from(step.after)),
+                ("hits", Json::from(step.hits.to_string())),
+            ])
+        })
+        .collect();
+
+    render_lines(&records)
+}
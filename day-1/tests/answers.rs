@@ -0,0 +1,7 @@
+use common::testing::check_answers;
+use day_1::Day1;
+
+#[test]
+fn answers_match_manifest() {
+    check_answers::<Day1>();
+}
@@ -0,0 +1,136 @@
+use day_1::dial::PREFIX_TABLE_LIMIT;
+use day_1::simulate::{click_by_click, simulate};
+use day_1::{Dial, Direction, Instruction, decode};
+
+mod common;
+
+use common::Rng;
+
+#[test]
+fn every_rotation_on_small_dials() {
+    for size in 1..=8 {
+        for targets in [vec![], vec![0], vec![size - 1], (0..size).collect()] {
+            for start in 0..size {
+                let dial = Dial::new(size, start, &targets).unwrap();
+
+                for current in 0..size {
+                    for rotation in 0..=u128::from(3 * size + 1) {
+                        for direction in [Direction::Right, Direction::Left] {
+                            assert_eq!(
+                                dial.count_hits_and_advance(current, rotation, direction),
+                                click_by_click(&dial, current, rotation, direction),
+                                "size {} targets {:?} at {} turning {}{}",
+                                size,
+                                targets,
+                                current,
+                                direction,
+                                rotation
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn random_rotations() {
+    let mut rng = Rng(0x5EED_0001);
+
+    for _ in 0..20_000 {
+        let dial = rng.dial(150);
+        let current = rng.below(u64::from(dial.size())) as u32;
+        let rotation = rng.rotation(dial.size());
+        let direction = rng.direction();
+
+        assert_eq!(
+            dial.count_hits_and_advance(current, rotation, direction),
+            click_by_click(&dial, current, rotation, direction),
+            "size {} targets {:?} at {} turning {}{}",
+            dial.size(),
+            dial.targets(),
+            current,
+            direction,
+            rotation
+        );
+    }
+}
+
+#[test]
+fn large_dials_without_a_lookup_table() {
+    let mut rng = Rng(0x5EED_0003);
+
+    for _ in 0..30 {
+        let size = PREFIX_TABLE_LIMIT + 1 + rng.below(1000) as u32;
+        let targets: Vec<u32> = (0..rng.below(50))
+            .map(|_| rng.below(u64::from(size)) as u32)
+            .collect();
+        let dial = Dial::new(size, 0, &targets).unwrap();
+        let current = rng.below(u64::from(size)) as u32;
+        let rotation = u128::from(rng.below(3 * u64::from(size)));
+        let direction = rng.direction();
+
+        assert_eq!(
+            dial.count_hits_and_advance(current, rotation, direction),
+            click_by_click(&dial, current, rotation, direction),
+            "size {} targets {:?} at {} turning {}{}",
+            size,
+            dial.targets(),
+            current,
+            direction,
+            rotation
+        );
+    }
+}
+
+#[test]
+fn random_instruction_streams() {
+    let mut rng = Rng(0x5EED_0002);
+
+    for _ in 0..500 {
+        let dial = rng.dial(120);
+        let instructions: Vec<Instruction> = (0..rng.below(40))
+            .map(|index| Instruction {
+                line: index as usize + 1,
+                dial: match rng.below(3) {
+                    0 => None,
+                    name => Some(name.to_string()),
+                },
+                direction: rng.direction(),
+                rotation: rng.rotation(dial.size()),
+            })
+            .collect();
+
+        for include_rotation_hits in [false, true] {
+            assert_eq!(
+                decode(&instructions, &dial, include_rotation_hits),
+                simulate(&instructions, &dial, include_rotation_hits),
+                "{:?} with {:?}",
+                dial,
+                instructions
+            );
+        }
+    }
+}
+
+#[test]
+fn puzzle_dial_matches_the_simulation() {
+    let dial = Dial::default();
+    let mut rng = Rng(0x5EED_0003);
+
+    for _ in 0..5_000 {
+        let current = rng.below(100) as u32;
+        let rotation = rng.rotation(100);
+        let direction = rng.direction();
+
+        assert_eq!(
+            dial.count_hits_and_advance(current, rotation, direction),
+            click_by_click(&dial, current, rotation, direction),
+            "at {} turning {}{}",
+            current,
+            direction,
+            rotation
+        );
+    }
+}
@@ -0,0 +1,68 @@
+#![allow(dead_code)]
+
+use day_1::{Dial, Direction};
+
+/// SplitMix64, so that every run checks the same cases
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-ish value in `0..bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    pub fn direction(&mut self) -> Direction {
+        if self.below(2) == 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        }
+    }
+
+    /// A dial of up to `max_size` positions with a random start and a random set of
+    /// targets, which may be empty or cover the whole dial
+    pub fn dial(&mut self, max_size: u64) -> Dial {
+        let size = 1 + self.below(max_size) as u32;
+        let start = self.below(u64::from(size)) as u32;
+        let targets: Vec<u32> = match self.below(4) {
+            0 => vec![],
+            1 => (0..size).collect(),
+            _ => (0..size).filter(|_| self.below(3) == 0).collect(),
+        };
+
+        Dial::new(size, start, &targets).unwrap()
+    }
+
+    /// Mostly short rotations, with a bias towards exact multiples of the dial size
+    /// and their neighbours, where the closed form is easiest to get wrong
+    pub fn rotation(&mut self, size: u32) -> u128 {
+        let size = u64::from(size);
+        let rotation = match self.below(3) {
+            0 => self.below(3 * size + 2),
+            1 => (self.below(5) * size + self.below(3)).saturating_sub(1),
+            _ => self.below(2000),
+        };
+
+        u128::from(rotation)
+    }
+
+    /// A code turning the unnamed dial and two named ones in random order
+    pub fn code(&mut self, length: usize) -> String {
+        (0..length)
+            .map(|_| {
+                let dial = ["", "a: ", "b: "][self.below(3) as usize];
+                let direction = ["R", "L"][self.below(2) as usize];
+                format!("{}{}{}", dial, direction, self.below(350))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,92 @@
+use day_1::{Dial, Direction, decode, parse_instructions};
+
+const MAX: &str = "340282366920938463463374607431768211455";
+
+#[test]
+fn parses_rotations_up_to_u128_max() {
+    let instructions = parse_instructions(&format!("R{}\nL{}", MAX, MAX)).unwrap();
+
+    assert_eq!(instructions[0].rotation, u128::MAX);
+    assert_eq!(instructions[1].rotation, u128::MAX);
+}
+
+#[test]
+fn rejects_rotations_beyond_u128_max() {
+    let error = parse_instructions("R340282366920938463463374607431768211456").unwrap_err();
+
+    assert_eq!((error.line, error.column), (1, 2));
+}
+
+#[test]
+fn rotations_beyond_u32_max() {
+    let dial = Dial::default();
+    let rotation = u128::from(u32::MAX) + 1;
+
+    // 4294967296 = 42949672 full turns plus 96 clicks, passing 0 once more from 50
+    assert_eq!(
+        dial.count_hits_and_advance(50, rotation, Direction::Right),
+        (42949673, 46)
+    );
+    assert_eq!(
+        dial.count_hits_and_advance(50, rotation, Direction::Left),
+        (42949673, 54)
+    );
+}
+
+#[test]
+fn rotations_of_u128_max() {
+    let dial = Dial::default();
+    let full_turns = u128::MAX / 100;
+
+    // u128::MAX leaves 55 clicks after the full turns, which pass 0 from 50 either way
+    assert_eq!(
+        dial.count_hits_and_advance(50, u128::MAX, Direction::Right),
+        (full_turns + 1, 5)
+    );
+    assert_eq!(
+        dial.count_hits_and_advance(50, u128::MAX, Direction::Left),
+        (full_turns + 1, 95)
+    );
+    assert_eq!(
+        dial.count_hits_and_advance(0, u128::MAX, Direction::Right),
+        (full_turns, 55)
+    );
+}
+
+#[test]
+fn every_position_a_target_hits_once_per_click() {
+    let targets: Vec<u32> = (0..7).collect();
+    let dial = Dial::new(7, 3, &targets).unwrap();
+
+    // u128::MAX leaves 3 clicks after the full turns
+    assert_eq!(
+        dial.count_hits_and_advance(3, u128::MAX, Direction::Right),
+        (u128::MAX, 6)
+    );
+    assert_eq!(
+        dial.count_hits_and_advance(3, u128::MAX, Direction::Left),
+        (u128::MAX, 0)
+    );
+}
+
+#[test]
+fn decodes_huge_rotations() {
+    let instructions = parse_instructions(&format!("R{}\nL{}\nR45", MAX, MAX)).unwrap();
+    let dial = Dial::default();
+
+    // 50 -> 5 -> 50 -> 95 never lands on 0
+    assert_eq!(decode(&instructions, &dial, false), 0);
+    assert_eq!(
+        decode(&instructions, &dial, true),
+        2 * (u128::MAX / 100 + 1)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Passcode does not fit in a u128")]
+fn passcode_beyond_u128_max_panics() {
+    let instructions = parse_instructions(&format!("R{}\nR1", MAX)).unwrap();
+    let dial = Dial::new(1, 0, &[0]).unwrap();
+
+    decode(&instructions, &dial, true);
+}
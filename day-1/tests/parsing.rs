@@ -0,0 +1,117 @@
+use day_1::{Direction, Instruction, MAX_SKIPPED_ERRORS, parse_code};
+
+fn parse_one(line: &str) -> Instruction {
+    let code = parse_code(line, true).unwrap();
+
+    assert_eq!(code.instructions.len(), 1, "{}", line);
+    code.instructions.into_iter().next().unwrap()
+}
+
+#[test]
+fn direction_aliases() {
+    let cases = [
+        ("R5", Direction::Right),
+        ("CW5", Direction::Right),
+        ("+5", Direction::Right),
+        ("L5", Direction::Left),
+        ("CCW5", Direction::Left),
+        ("-5", Direction::Left),
+    ];
+
+    for (line, direction) in cases {
+        let instruction = parse_one(line);
+
+        assert_eq!(instruction.direction, direction, "{}", line);
+        assert_eq!(instruction.rotation, 5, "{}", line);
+        assert_eq!(instruction.dial, None, "{}", line);
+    }
+}
+
+#[test]
+fn named_dials() {
+    let instruction = parse_one("  door : CCW10");
+
+    assert_eq!(instruction.dial.as_deref(), Some("door"));
+    assert_eq!(instruction.direction, Direction::Left);
+    assert_eq!(instruction.rotation, 10);
+}
+
+#[test]
+fn rotation_must_not_carry_a_sign() {
+    for (line, column, text) in [("R-5", 2, "-5"), ("--5", 2, "-5"), ("CW+5", 3, "+5")] {
+        let error = parse_code(line, false).unwrap_err();
+
+        assert_eq!(error.message, "Invalid rotation number", "{}", line);
+        assert_eq!((error.line, error.column), (1, column), "{}", line);
+        assert_eq!(error.text, text, "{}", line);
+    }
+}
+
+#[test]
+fn strict_mode_rejects_unknown_directions() {
+    let error = parse_code("R5\n\n  X5\nL3", true).unwrap_err();
+
+    assert_eq!(error.message, "Unknown direction");
+    assert_eq!((error.line, error.column), (3, 3));
+    assert_eq!(error.text, "X5");
+    assert_eq!(error.source_line, "  X5");
+
+    let error = parse_code("door: X5", true).unwrap_err();
+
+    assert_eq!((error.line, error.column), (1, 7));
+    assert_eq!(error.text, "X5");
+}
+
+#[test]
+fn lenient_mode_skips_unknown_directions() {
+    let code = parse_code("R5\nX5\n\nY7\nL3", false).unwrap();
+
+    assert_eq!(code.instructions.len(), 2);
+    assert_eq!(
+        code.instructions
+            .iter()
+            .map(|instruction| instruction.line)
+            .collect::<Vec<_>>(),
+        [1, 5]
+    );
+
+    assert_eq!(code.skipped.count, 2);
+    let skipped: Vec<(usize, &str)> = code
+        .skipped
+        .errors
+        .iter()
+        .map(|error| (error.line, error.text.as_str()))
+        .collect();
+    assert_eq!(skipped, [(2, "X5"), (4, "Y7")]);
+}
+
+#[test]
+fn lenient_mode_keeps_only_the_first_errors() {
+    let code = parse_code(&"X5\nR1\n".repeat(25), false).unwrap();
+
+    assert_eq!(code.instructions.len(), 25);
+    assert_eq!(code.skipped.count, 25);
+    assert_eq!(code.skipped.errors.len(), MAX_SKIPPED_ERRORS);
+    assert_eq!(code.skipped.errors[MAX_SKIPPED_ERRORS - 1].line, 19);
+}
+
+#[test]
+fn dial_prefix_needs_a_name_and_an_instruction() {
+    for strict in [true, false] {
+        let error = parse_code("R5\n :R3", strict).unwrap_err();
+
+        assert_eq!(error.message, "Missing dial name");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, ":R3");
+
+        let error = parse_code("door:", strict).unwrap_err();
+
+        assert_eq!(error.message, "Missing instruction");
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = parse_code("door:   ", strict).unwrap_err();
+
+        assert_eq!(error.message, "Missing instruction");
+        assert_eq!(error.text, "door:");
+    }
+}
@@ -0,0 +1,98 @@
+use day_1::stats::{stats, to_report};
+use day_1::{Dial, parse_instructions};
+use std::collections::BTreeMap;
+
+/// Three dials on a dial of 10 positions with a single target at 0, where only line 4
+/// points at the target, passing it without stopping there
+const CODE: &str = "R3\na: L2\nR4\na: R5\nb: R3\nL4";
+
+#[test]
+fn landings_per_position() {
+    let dial = Dial::new(10, 0, &[0]).unwrap();
+    let stats = stats(&parse_instructions(CODE).unwrap(), &dial);
+
+    assert_eq!(stats.instructions, 6);
+    assert_eq!(stats.landings, BTreeMap::from([(3, 4), (7, 1), (8, 1)]));
+    assert_eq!(stats.most_visited(), [3]);
+    assert_eq!(stats.least_visited(), [7, 8]);
+    assert_eq!(stats.never_visited(), 7);
+}
+
+#[test]
+fn travel_per_direction() {
+    let dial = Dial::new(10, 0, &[0]).unwrap();
+    let stats = stats(&parse_instructions(CODE).unwrap(), &dial);
+
+    assert_eq!((stats.clockwise, stats.counter_clockwise), (15, 6));
+}
+
+#[test]
+fn passing_a_target_ends_a_run() {
+    let dial = Dial::new(10, 0, &[0]).unwrap();
+    let stats = stats(&parse_instructions(CODE).unwrap(), &dial);
+
+    assert_eq!(stats.longest_miss, Some((1, 3)));
+    assert_eq!(stats.longest_miss_length, 3);
+}
+
+#[test]
+fn stopping_on_a_target_ends_a_run() {
+    let dial = Dial::new(10, 0, &[5]).unwrap();
+    let stats = stats(&parse_instructions("R1\nR4\nL1\nL2\nL3").unwrap(), &dial);
+
+    assert_eq!(stats.longest_miss, Some((3, 5)));
+    assert_eq!(stats.longest_miss_length, 3);
+}
+
+#[test]
+fn code_without_target_hits() {
+    let instructions = parse_instructions("R1\n\nR2\nL1\nR1").unwrap();
+
+    for targets in [vec![5], vec![]] {
+        let dial = Dial::new(10, 0, &targets).unwrap();
+        let stats = stats(&instructions, &dial);
+
+        assert_eq!(stats.longest_miss, Some((1, 5)), "{:?}", targets);
+        assert_eq!(stats.longest_miss_length, 4, "{:?}", targets);
+        assert_eq!(stats.landings, BTreeMap::from([(1, 1), (2, 1), (3, 2)]));
+    }
+}
+
+#[test]
+fn empty_code() {
+    let stats = stats(&[], &Dial::default());
+
+    assert_eq!(stats.instructions, 0);
+    assert_eq!(stats.longest_miss, None);
+    assert_eq!(stats.longest_miss_length, 0);
+    assert!(stats.most_visited().is_empty());
+    assert!(stats.least_visited().is_empty());
+    assert_eq!(stats.never_visited(), 100);
+    assert!(to_report(&stats).contains("Longest run without a target: 0 instructions\n"));
+}
+
+#[test]
+fn report_with_histogram() {
+    let dial = Dial::new(10, 0, &[0]).unwrap();
+    let stats = stats(&parse_instructions(CODE).unwrap(), &dial);
+
+    assert_eq!(
+        to_report(&stats),
+        format!(
+            "Instructions: 6\n\
+             Clockwise travel: 15 clicks\n\
+             Counter-clockwise travel: 6 clicks\n\
+             Longest run without a target: 3 instructions (lines 1 to 3)\n\
+             Most visited: 3 (4 landings)\n\
+             Least visited: 7, 8 (1 landings)\n\
+             Never visited: 7 positions\n\
+             Landings per position:\n\
+             3 4 {}\n\
+             7 1 {}\n\
+             8 1 {}\n",
+            "#".repeat(50),
+            "#".repeat(13),
+            "#".repeat(13)
+        )
+    );
+}
@@ -0,0 +1,114 @@
+use day_1::stream::decode_stream;
+use day_1::{Dial, decode_dials, parse_code};
+use std::io::Cursor;
+
+mod common;
+
+use common::Rng;
+
+/// A code file over the unnamed dial and two named ones, with blank lines, lines in
+/// unknown directions, mixed LF and CRLF endings, and maybe no final line ending
+fn code_file(rng: &mut Rng, length: usize) -> String {
+    let mut file = String::new();
+
+    for line in 0..length {
+        match rng.below(10) {
+            0 => {}
+            1 => file.push_str(["X5", "b: Q12", "  ?3"][rng.below(3) as usize]),
+            _ => file.push_str(&rng.code(1)),
+        }
+
+        if line + 1 < length || rng.below(2) == 0 {
+            file.push_str(["\n", "\r\n"][rng.below(2) as usize]);
+        }
+    }
+
+    file
+}
+
+fn check_against_decode(file: &str, dial: &Dial, strict: bool) {
+    let streamed = decode_stream(Cursor::new(file), dial, strict);
+
+    match parse_code(file, strict) {
+        Ok(code) => {
+            let passcodes = streamed.unwrap();
+
+            assert_eq!(
+                passcodes.landings,
+                decode_dials(&code.instructions, dial, false),
+                "{:?}",
+                file
+            );
+            assert_eq!(
+                passcodes.hits,
+                decode_dials(&code.instructions, dial, true),
+                "{:?}",
+                file
+            );
+            assert_eq!(passcodes.skipped, code.skipped, "{:?}", file);
+        }
+        Err(error) => {
+            let streamed = streamed.unwrap_err().to_string();
+
+            assert_eq!(streamed, error.to_string(), "{:?}", file);
+        }
+    }
+}
+
+#[test]
+fn matches_decode_on_random_code_files() {
+    let mut rng = Rng(0x5EED_0005);
+
+    for _ in 0..300 {
+        let dial = rng.dial(40);
+        let length = rng.below(30) as usize;
+        let file = code_file(&mut rng, length);
+
+        for strict in [true, false] {
+            check_against_decode(&file, &dial, strict);
+        }
+    }
+}
+
+#[test]
+fn crlf_endings_and_a_missing_final_newline() {
+    let dial = Dial::default();
+
+    for file in [
+        "L68\r\na: R48\r\nL5",
+        "L68\r\na: R48\r\nL5\r\n",
+        "L68\na: R48\r\nL5\n",
+    ] {
+        let passcodes = decode_stream(Cursor::new(file), &dial, true).unwrap();
+        let counts: Vec<(Option<&str>, u128, u128)> = passcodes
+            .landings
+            .iter()
+            .zip(&passcodes.hits)
+            .map(|(landings, hits)| (landings.name.as_deref(), landings.count, hits.count))
+            .collect();
+
+        assert_eq!(counts, [(None, 0, 1), (Some("a"), 0, 0)], "{:?}", file);
+    }
+}
+
+#[test]
+fn lenient_mode_counts_skipped_lines() {
+    let file = "R5\r\nX5\r\n\r\nY7\nL3";
+    let passcodes = decode_stream(Cursor::new(file), &Dial::default(), false).unwrap();
+
+    assert_eq!(passcodes.skipped.count, 2);
+    let skipped: Vec<(usize, &str)> = passcodes
+        .skipped
+        .errors
+        .iter()
+        .map(|error| (error.line, error.text.as_str()))
+        .collect();
+    assert_eq!(skipped, [(2, "X5"), (4, "Y7")]);
+
+    let error = decode_stream(Cursor::new(file), &Dial::default(), true).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        parse_code(file, true).unwrap_err().to_string()
+    );
+}
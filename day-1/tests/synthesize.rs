@@ -0,0 +1,126 @@
+use day_1::synthesize::synthesize;
+use day_1::{Dial, Direction, decode};
+
+mod common;
+
+use common::Rng;
+
+/// Synthesizes the passcode for both parts and checks that the code replays to it in
+/// as few instructions as possible
+fn check_both_parts(dial: &Dial, passcode: u128) {
+    let instructions = synthesize(dial, passcode, false, usize::MAX).unwrap();
+
+    assert_eq!(decode(&instructions, dial, false), passcode, "part 1");
+    assert_eq!(instructions.len() as u128, passcode, "part 1");
+
+    let instructions = synthesize(dial, passcode, true, usize::MAX).unwrap();
+
+    assert_eq!(decode(&instructions, dial, true), passcode, "part 2");
+    assert_eq!(instructions.len(), usize::from(passcode > 0), "part 2");
+}
+
+#[test]
+fn replays_on_hand_picked_dials() {
+    let dials = [
+        Dial::default(),
+        Dial::new(100, 7, &[0]).unwrap(),
+        Dial::new(10, 3, &[1, 4, 8]).unwrap(),
+        Dial::new(10, 4, &[1, 4, 8]).unwrap(),
+        Dial::new(1, 0, &[0]).unwrap(),
+        Dial::new(5, 2, &[0, 1, 2, 3, 4]).unwrap(),
+    ];
+
+    for dial in &dials {
+        for passcode in [1, 2, 3, 4, 7, 12, 100] {
+            check_both_parts(dial, passcode);
+        }
+    }
+}
+
+#[test]
+fn replays_on_random_dials() {
+    let mut rng = Rng(0x5EED_0004);
+
+    for _ in 0..300 {
+        let dial = rng.dial(50);
+        if dial.targets().is_empty() {
+            continue;
+        }
+
+        check_both_parts(&dial, u128::from(1 + rng.below(60)));
+    }
+}
+
+#[test]
+fn every_instruction_turns_the_unnamed_dial_clockwise() {
+    let dial = Dial::new(10, 3, &[1, 4, 8]).unwrap();
+
+    for include_rotation_hits in [false, true] {
+        let instructions = synthesize(&dial, 5, include_rotation_hits, 10).unwrap();
+
+        for (line, instruction) in (1..).zip(&instructions) {
+            assert_eq!(instruction.line, line);
+            assert_eq!(instruction.dial, None);
+            assert_eq!(instruction.direction, Direction::Right);
+        }
+    }
+}
+
+#[test]
+fn zero_passcode_needs_no_instructions() {
+    for dial in [Dial::default(), Dial::new(10, 0, &[]).unwrap()] {
+        for include_rotation_hits in [false, true] {
+            assert_eq!(synthesize(&dial, 0, include_rotation_hits, 0), Some(vec![]));
+        }
+    }
+}
+
+#[test]
+fn no_targets_means_no_code() {
+    let dial = Dial::new(10, 0, &[]).unwrap();
+
+    for include_rotation_hits in [false, true] {
+        assert_eq!(synthesize(&dial, 1, include_rotation_hits, 100), None);
+    }
+}
+
+#[test]
+fn respects_the_maximum_length() {
+    let dial = Dial::default();
+
+    assert_eq!(synthesize(&dial, 5, false, 4), None);
+    assert_eq!(synthesize(&dial, 5, false, 5).unwrap().len(), 5);
+    assert_eq!(synthesize(&dial, 5, true, 0), None);
+    assert_eq!(synthesize(&dial, 5, true, 1).unwrap().len(), 1);
+    assert_eq!(synthesize(&dial, u128::MAX, false, usize::MAX), None);
+}
+
+#[test]
+fn splits_rotations_too_large_for_a_u128() {
+    let dial = Dial::new(1, 0, &[0]).unwrap();
+    let instructions = synthesize(&dial, u128::MAX, true, 10).unwrap();
+
+    assert_eq!(instructions.len(), 1);
+    assert_eq!(decode(&instructions, &dial, true), u128::MAX);
+
+    // 50 + (2^128 - 2) * 100 clicks, i.e. 100 * (2^128 - 1) - 50, take 100 rotations
+    let dial = Dial::default();
+    let instructions = synthesize(&dial, u128::MAX, true, 100).unwrap();
+
+    assert_eq!(instructions.len(), 100);
+    assert!(
+        instructions[..99]
+            .iter()
+            .all(|instruction| instruction.rotation == u128::MAX)
+    );
+    assert_eq!(instructions[99].rotation, u128::MAX - 50);
+    assert_eq!(decode(&instructions, &dial, true), u128::MAX);
+    assert_eq!(synthesize(&dial, u128::MAX, true, 99), None);
+
+    // Two targets halve the clicks
+    let dial = Dial::new(100, 50, &[0, 50]).unwrap();
+    let instructions = synthesize(&dial, u128::MAX, true, 100).unwrap();
+
+    assert_eq!(instructions.len(), 50);
+    assert_eq!(decode(&instructions, &dial, true), u128::MAX);
+}
@@ -0,0 +1,172 @@
+use day_1::timeline::Timeline;
+use day_1::trace::{TraceStep, trace};
+use day_1::{Dial, parse_instructions};
+
+mod common;
+
+use common::Rng;
+
+/// Position of the dial after the first `step` steps of the trace, if the lock has it
+fn traced_position(
+    steps: &[TraceStep],
+    dial: &Dial,
+    name: Option<&str>,
+    step: usize,
+) -> Option<u32> {
+    if !traced_dial(steps, name) {
+        return None;
+    }
+
+    Some(
+        steps[..step]
+            .iter()
+            .rev()
+            .find(|traced| traced.dial.as_deref() == name)
+            .map_or(dial.start(), |traced| traced.after),
+    )
+}
+
+/// Whether the lock has the dial: the unnamed one always, named ones if any step turns
+/// them
+fn traced_dial(steps: &[TraceStep], name: Option<&str>) -> bool {
+    name.is_none() || steps.iter().any(|traced| traced.dial.as_deref() == name)
+}
+
+/// Hits and landings of the steps `from + 1..=to` of the trace turning a dial that
+/// `counts` accepts
+fn traced_counts(
+    steps: &[TraceStep],
+    dial: &Dial,
+    from: usize,
+    to: usize,
+    counts: impl Fn(&TraceStep) -> bool,
+) -> (u128, u128) {
+    steps[from..to]
+        .iter()
+        .filter(|&traced| counts(traced))
+        .fold((0, 0), |(hits, landings), traced| {
+            (
+                hits + traced.hits,
+                landings + u128::from(dial.is_target(traced.after)),
+            )
+        })
+}
+
+fn check_against_trace(code: &str, dial: &Dial) {
+    let instructions = parse_instructions(code).unwrap();
+    let steps = trace(&instructions, dial);
+    let timeline = Timeline::new(&instructions, dial);
+
+    assert_eq!(timeline.len(), steps.len());
+
+    for step in 0..=steps.len() {
+        for name in [None, Some("a"), Some("b"), Some("unused")] {
+            assert_eq!(
+                timeline.position_at(name, step),
+                traced_position(&steps, dial, name, step),
+                "{:?} at step {}",
+                name,
+                step
+            );
+        }
+    }
+
+    for from in 0..=steps.len() {
+        for to in from..=steps.len() {
+            assert_eq!(
+                (
+                    timeline.hits_between(from, to),
+                    timeline.landings_between(from, to)
+                ),
+                traced_counts(&steps, dial, from, to, |_| true),
+                "steps {} to {}",
+                from,
+                to
+            );
+
+            for name in [None, Some("a"), Some("b"), Some("unused")] {
+                assert_eq!(
+                    timeline
+                        .dial_hits_between(name, from, to)
+                        .zip(timeline.dial_landings_between(name, from, to)),
+                    traced_dial(&steps, name).then(|| {
+                        traced_counts(&steps, dial, from, to, |traced| {
+                            traced.dial.as_deref() == name
+                        })
+                    }),
+                    "{:?} steps {} to {}",
+                    name,
+                    from,
+                    to
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn matches_the_trace() {
+    let dial = Dial::default();
+
+    check_against_trace("L68\na: R10\nL30\nb: L55\na: R40\nR48", &dial);
+}
+
+#[test]
+fn matches_the_trace_on_random_codes() {
+    let mut rng = Rng(0xDA7_0001);
+
+    for _ in 0..50 {
+        let dial = rng.dial(20);
+        let length = rng.below(25) as usize;
+
+        check_against_trace(&rng.code(length), &dial);
+    }
+}
+
+#[test]
+fn step_zero_is_before_any_instruction() {
+    let dial = Dial::default();
+    let instructions = parse_instructions("R50\na: L50").unwrap();
+    let timeline = Timeline::new(&instructions, &dial);
+
+    assert_eq!(timeline.position_at(None, 0), Some(50));
+    assert_eq!(timeline.position_at(Some("a"), 0), Some(50));
+    assert_eq!(timeline.hits_between(0, 0), 0);
+    assert_eq!(timeline.dial_hits_between(Some("a"), 0, 0), Some(0));
+
+    assert_eq!(timeline.position_at(None, 1), Some(0));
+    assert_eq!(timeline.position_at(Some("a"), 1), Some(50));
+    assert_eq!(timeline.dial_hits_between(None, 0, 2), Some(1));
+    assert_eq!(timeline.dial_hits_between(Some("a"), 0, 2), Some(1));
+    assert_eq!(timeline.hits_between(0, 2), 2);
+}
+
+#[test]
+fn empty_code() {
+    let timeline = Timeline::new(&[], &Dial::default());
+
+    assert!(timeline.is_empty());
+    assert_eq!(timeline.position_at(None, 0), Some(50));
+    assert_eq!(timeline.landings_between(0, 0), 0);
+}
+
+#[test]
+fn unknown_dials_have_no_answer() {
+    let instructions = parse_instructions(
+        "a: R50
+L10",
+    )
+    .unwrap();
+    let timeline = Timeline::new(&instructions, &Dial::default());
+
+    assert!(timeline.has_dial(None));
+    assert!(timeline.has_dial(Some("a")));
+    assert!(!timeline.has_dial(Some("b")));
+    assert_eq!(timeline.position_at(Some("b"), 2), None);
+    assert_eq!(timeline.dial_hits_between(Some("b"), 0, 2), None);
+    assert_eq!(timeline.dial_landings_between(Some("b"), 0, 2), None);
+
+    let timeline = Timeline::new(&[], &Dial::default());
+
+    assert_eq!(timeline.position_at(Some("a"), 0), None);
+}
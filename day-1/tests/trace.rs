@@ -0,0 +1,59 @@
+use day_1::trace::{to_csv, to_json, trace};
+use day_1::{Dial, parse_instructions};
+
+const MAX: &str = "340282366920938463463374607431768211455";
+
+#[test]
+fn csv_header_and_columns() {
+    let instructions = parse_instructions("L68\nR48").unwrap();
+    let steps = trace(&instructions, &Dial::default());
+
+    assert_eq!(
+        to_csv(&steps),
+        "line,dial,direction,rotation,before,after,hits\n\
+         1,,L,68,50,82,1\n\
+         2,,R,48,82,30,1\n"
+    );
+}
+
+#[test]
+fn csv_quotes_dial_names() {
+    let instructions = parse_instructions("front,left: R50\nsay \"hi\": L10").unwrap();
+    let steps = trace(&instructions, &Dial::default());
+
+    assert_eq!(
+        to_csv(&steps),
+        "line,dial,direction,rotation,before,after,hits\n\
+         1,\"front,left\",R,50,50,0,1\n\
+         2,\"say \"\"hi\"\"\",L,10,50,40,0\n"
+    );
+}
+
+#[test]
+fn json_records() {
+    let instructions = parse_instructions("L68\ndoor: R50").unwrap();
+    let steps = trace(&instructions, &Dial::default());
+
+    assert_eq!(
+        to_json(&steps),
+        "[\n  \
+         {\"line\":1,\"dial\":null,\"direction\":\"L\",\"rotation\":\"68\",\"before\":50,\"after\":82,\"hits\":\"1\"},\n  \
+         {\"line\":2,\"dial\":\"door\",\"direction\":\"R\",\"rotation\":\"50\",\"before\":50,\"after\":0,\"hits\":\"1\"}\n\
+         ]"
+    );
+}
+
+#[test]
+fn large_rotations_keep_their_precision() {
+    let instructions = parse_instructions(&format!("R{}", MAX)).unwrap();
+    let steps = trace(&instructions, &Dial::new(1, 0, &[0]).unwrap());
+
+    assert_eq!(steps[0].hits, u128::MAX);
+    assert!(
+        to_json(&steps).contains(&format!("\"rotation\":\"{}\"", MAX)),
+        "{}",
+        to_json(&steps)
+    );
+    assert!(to_json(&steps).contains(&format!("\"hits\":\"{}\"", MAX)));
+    assert!(to_csv(&steps).ends_with(&format!(",R,{},0,0,{}\n", MAX, MAX)));
+}
@@ -0,0 +1,167 @@
+pub mod pattern;
+pub mod report;
+pub mod rule;
+
+pub use rule::Rule;
+
+use common::{ParseError, Solution};
+
+/// Whether the product ID consists of a repeated sequence of digits: exactly twice
+/// when `max_twice` is set, at least twice otherwise. [`Rule`] covers other kinds of
+/// repetition.
+pub fn is_repeated_digit_pattern(product_id: &str, max_twice: bool) -> bool {
+    let product_id_length = product_id.len();
+
+    if max_twice {
+        if product_id_length <= 1 {
+            return false;
+        }
+
+        let half = product_id_length / 2;
+        let first = &product_id[..half];
+        let second = &product_id[half..];
+
+        return first == second;
+    }
+
+    if product_id_length <= 1 {
+        return false;
+    }
+
+    let test_value = format!("{product_id}{product_id}");
+    test_value[1..test_value.len() - 1].contains(product_id)
+}
+
+/// Inclusive range of product IDs, e.g. `11-22`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdRange {
+    pub lower: u64,
+    pub upper: u64,
+}
+
+/// Trims `text`, found at byte `offset` of the input, returning the offset of what
+/// remains along with it
+fn trim_at(offset: usize, text: &str) -> (usize, &str) {
+    (offset + text.len() - text.trim_start().len(), text.trim())
+}
+
+/// Parses a bound along with its byte offset in the input, or reports the range at
+/// `range_offset` as missing it
+fn parse_bound(
+    id_ranges: &str,
+    bound: Option<(usize, &str)>,
+    (range_offset, id_range): (usize, &str),
+    name: &str,
+) -> Result<u64, ParseError> {
+    let Some((offset, bound)) = bound.filter(|(_, bound)| !bound.is_empty()) else {
+        return Err(error_at(
+            id_ranges,
+            range_offset,
+            id_range.len(),
+            format!("Missing {} bound", name),
+        ));
+    };
+
+    bound.parse().map_err(|_| {
+        error_at(
+            id_ranges,
+            offset,
+            bound.len(),
+            format!("Invalid {} bound", name),
+        )
+    })
+}
+
+/// Locates the `len` bytes at byte `offset` of the input by line and column
+fn error_at(id_ranges: &str, offset: usize, len: usize, message: String) -> ParseError {
+    let line_start = id_ranges[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line_number = id_ranges[..offset].matches('\n').count() + 1;
+    let source_line = id_ranges[line_start..].lines().next().unwrap_or("");
+
+    ParseError::at(line_number, source_line, offset - line_start, len, message)
+}
+
+/// Parses the list of ID ranges, separated by commas or newlines. Whitespace around
+/// ranges and their bounds is ignored, as are empty entries, and reversed bounds are
+/// swapped.
+pub fn parse_id_ranges(id_ranges: &str) -> Result<Vec<IdRange>, ParseError> {
+    let mut ranges: Vec<IdRange> = vec![];
+    let mut entry_offset = 0;
+
+    for entry in id_ranges.split([',', '\n']) {
+        let range = trim_at(entry_offset, entry);
+        entry_offset += entry.len() + 1;
+
+        let (offset, id_range) = range;
+        if id_range.is_empty() {
+            continue;
+        }
+
+        let (lower, upper) = match id_range.split_once('-') {
+            Some((lower, upper)) => (
+                trim_at(offset, lower),
+                Some(trim_at(offset + lower.len() + 1, upper)),
+            ),
+            None => ((offset, id_range), None),
+        };
+
+        let lower = parse_bound(id_ranges, Some(lower), range, "lower")?;
+        let upper = parse_bound(id_ranges, upper, range, "upper")?;
+
+        ranges.push(IdRange {
+            lower: lower.min(upper),
+            upper: lower.max(upper),
+        });
+    }
+
+    Ok(ranges)
+}
+
+/// Merges overlapping ranges so that no ID lies in more than one, sorted by their
+/// lower bound
+pub fn merge_ranges(id_ranges: &[IdRange]) -> Vec<IdRange> {
+    let mut ranges = id_ranges.to_vec();
+    ranges.sort_unstable_by_key(|range| range.lower);
+
+    let mut merged: Vec<IdRange> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.lower <= last.upper => last.upper = last.upper.max(range.upper),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Sums every product ID within the ranges that follows the rule, counting IDs in
+/// overlapping ranges once per range; see [`merge_ranges`]
+pub fn calculate_code(id_ranges: &[IdRange], rule: &Rule) -> u128 {
+    id_ranges
+        .iter()
+        .map(|&IdRange { lower, upper }| pattern::sum_repeated_ids(lower, upper, rule))
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = "id-ranges.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<IdRange>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_id_ranges(input)
+    }
+
+    fn part1(id_ranges: &Self::Input) -> Self::Answer {
+        calculate_code(&merge_ranges(id_ranges), &Rule::exactly_twice())
+    }
+
+    fn part2(id_ranges: &Self::Input) -> Self::Answer {
+        calculate_code(&merge_ranges(id_ranges), &Rule::at_least_twice())
+    }
+}
@@ -1,61 +1,240 @@
-use regex::Regex;
-use std::fs;
+use common::cli::{parse_options, parse_value};
+use common::input::load_input;
+use day_2::pattern::repeated_ids;
+use day_2::report::{report, to_csv, to_json, to_table};
+use day_2::{Day2, IdRange, Rule, calculate_code, merge_ranges};
+use std::env;
+use std::process;
 
-fn is_repeated_digit_pattern(product_id: &str, max_twice: bool) -> bool {
-    let product_id_length = product_id.len();
+const USAGE: &str = "Usage:
+    day-2 [--verbose] [--keep-overlaps] [RULE OPTIONS] [INPUT]
+    day-2 report [--format table|csv|json] [RULE OPTIONS] [INPUT]
 
-    if max_twice {
-        if product_id_length <= 1 {
-            return false;
-        }
+Overlapping ranges are merged before summing them so that every ID counts once,
+unless --keep-overlaps is given. A report lists the invalid IDs of every range as
+given in the input.
+
+Without rule options both puzzle rules are applied, or only the second one for a
+report. Rule options start from a unit repeated at least twice:
+    --repetitions N         Unit repeated exactly N times
+    --min-repetitions N     Unit repeated at least N times
+    --max-repetitions N     Unit repeated at most N times
+    --unit-lengths A,B,...  Allowed numbers of digits of the unit
+    --pad-width N           Zero-pad IDs to N digits before checking them, at most the
+                            digits of the largest ID in the radix
+    --radix N               Check IDs written in base N, from 2 to 36 (default 10)
+
+Range bounds are always read in base 10.";
 
-        let half = product_id_length / 2;
-        let first = &product_id[..half];
-        let second = &product_id[half..];
+enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+enum Command {
+    Solve { verbose: bool, keep_overlaps: bool },
+    Report { format: Format },
+}
 
-        return first == second;
+/// Rule options as given, checked once they are all known
+struct RuleOptions {
+    min_repetitions: u32,
+    max_repetitions: Option<u32>,
+    unit_lengths: Option<Vec<u32>>,
+    pad_width: Option<u32>,
+    radix: Option<u32>,
+}
+
+impl Default for RuleOptions {
+    fn default() -> Self {
+        RuleOptions {
+            min_repetitions: 2,
+            max_repetitions: None,
+            unit_lengths: None,
+            pad_width: None,
+            radix: None,
+        }
     }
+}
+
+impl RuleOptions {
+    fn build(self) -> Result<Rule, String> {
+        let mut rule = Rule::new(self.min_repetitions, self.max_repetitions)?;
 
-    if product_id_length <= 1 {
-        return false;
+        if let Some(unit_lengths) = self.unit_lengths {
+            rule = rule.with_unit_lengths(unit_lengths)?;
+        }
+        if let Some(radix) = self.radix {
+            rule = rule.with_radix(radix)?;
+        }
+        if let Some(width) = self.pad_width {
+            rule = rule.with_pad_width(width)?;
+        }
+
+        Ok(rule)
     }
+}
 
-    let test_value = format!("{product_id}{product_id}");
-    test_value[1..test_value.len() - 1].contains(product_id)
+struct Args<'a> {
+    command: Command,
+    rule: Option<Rule>,
+    path: Option<&'a str>,
 }
 
-fn calculate_code(max_twice: bool) -> u64 {
-    let mut final_code = 0;
-    let matching_re = Regex::new(r"^\d+$").unwrap();
-    let id_ranges =
-        fs::read_to_string("day-2/id-ranges.txt").expect("No id-ranges.txt file found!");
+fn parse_rule_option(rule: &mut RuleOptions, option: &str, value: &str) -> Result<(), String> {
+    match option {
+        "--repetitions" => {
+            let repetitions = parse_value(option, value)?;
+            rule.min_repetitions = repetitions;
+            rule.max_repetitions = Some(repetitions);
+        }
+        "--min-repetitions" => rule.min_repetitions = parse_value(option, value)?,
+        "--max-repetitions" => rule.max_repetitions = Some(parse_value(option, value)?),
+        "--unit-lengths" => {
+            rule.unit_lengths = Some(
+                value
+                    .split(',')
+                    .map(|unit_length| parse_value(option, unit_length.trim()))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        "--pad-width" => rule.pad_width = Some(parse_value(option, value)?),
+        "--radix" => rule.radix = Some(parse_value(option, value)?),
+        _ => return Err(format!("Unexpected argument '{}'", option)),
+    }
 
-    for id_range in id_ranges.split(",") {
-        let bounds: Vec<&str> = id_range.split("-").collect();
+    Ok(())
+}
 
-        let lower: u64 = bounds[0].parse().unwrap();
-        let upper: u64 = bounds[1].parse().unwrap();
+fn parse_args(args: &[String]) -> Result<Args<'_>, String> {
+    let (mut command, options) = match args.split_first() {
+        Some((command, options)) if command == "report" => (
+            Command::Report {
+                format: Format::Table,
+            },
+            options,
+        ),
+        _ => (
+            Command::Solve {
+                verbose: false,
+                keep_overlaps: false,
+            },
+            args,
+        ),
+    };
 
-        // Note: upper+1 to include the upper bound in the iteration
-        for i in lower..upper + 1 {
-            let product_id = i.to_string();
+    let mut rule: Option<RuleOptions> = None;
+    let mut path: Option<&str> = None;
 
-            if matching_re.is_match(&product_id)
-                && is_repeated_digit_pattern(&product_id, max_twice)
-            {
-                final_code += i;
-                println!("Checking Product ID: {}... ", i);
+    let options = parse_options(
+        options,
+        &[
+            "--format",
+            "--repetitions",
+            "--min-repetitions",
+            "--max-repetitions",
+            "--unit-lengths",
+            "--pad-width",
+            "--radix",
+        ],
+    )?;
+
+    for (option, value) in options {
+        match (option, value, &mut command) {
+            ("--verbose", None, Command::Solve { verbose, .. }) => *verbose = true,
+            ("--keep-overlaps", None, Command::Solve { keep_overlaps, .. }) => {
+                *keep_overlaps = true
+            }
+            ("--format", Some(value), Command::Report { format }) => {
+                *format = match value {
+                    "table" => Format::Table,
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    _ => return Err(format!("Unknown format '{}'", value)),
+                }
             }
+            ("--format", Some(_), _) => return Err(format!("Unexpected argument '{}'", option)),
+            (_, Some(value), _) => {
+                parse_rule_option(rule.get_or_insert_with(RuleOptions::default), option, value)?
+            }
+            (arg, None, _) if path.is_none() && (arg == "-" || !arg.starts_with("--")) => {
+                path = Some(arg)
+            }
+            _ => return Err(format!("Unexpected argument '{}'", option)),
         }
     }
 
-    final_code
+    Ok(Args {
+        command,
+        rule: rule.map(RuleOptions::build).transpose()?,
+        path,
+    })
+}
+
+/// Lists every invalid ID on stderr, keeping the codes on stdout
+fn print_invalid_ids(id_ranges: &[IdRange], rule: &Rule) {
+    for &IdRange { lower, upper } in id_ranges {
+        for id in repeated_ids(lower, upper, rule) {
+            eprintln!("Checking Product ID: {}... ", rule.format(id));
+        }
+    }
+}
+
+/// Prints the final code for the given rule, or for both puzzle rules
+fn solve(id_ranges: &[IdRange], rule: Option<Rule>, verbose: bool) {
+    match rule {
+        Some(rule) => {
+            if verbose {
+                print_invalid_ids(id_ranges, &rule);
+            }
+
+            println!("Final code: {}", calculate_code(id_ranges, &rule));
+        }
+        None => {
+            if verbose {
+                print_invalid_ids(id_ranges, &Rule::exactly_twice());
+            }
+            let final_code_1 = calculate_code(id_ranges, &Rule::exactly_twice());
+
+            if verbose {
+                print_invalid_ids(id_ranges, &Rule::at_least_twice());
+            }
+            let final_code_2 = calculate_code(id_ranges, &Rule::at_least_twice());
+
+            println!("Max. 2 repetitions - Final code: {}", final_code_1);
+            println!("At least 2 repetitions - Final code: {}", final_code_2);
+        }
+    }
 }
 
 fn main() {
-    let final_code_1: u64 = calculate_code(true);
-    let final_code_2: u64 = calculate_code(false);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Args {
+        command,
+        rule,
+        path,
+    } = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("{}\n\n{}", error, USAGE);
+        process::exit(2);
+    });
 
-    println!("Max. 2 repetitions - Final code: {}", final_code_1);
-    println!("At least 2 repetitions - Final code: {}", final_code_2);
+    let id_ranges = load_input::<Day2>(path);
+
+    match command {
+        Command::Solve {
+            verbose,
+            keep_overlaps: true,
+        } => solve(&id_ranges, rule, verbose),
+        Command::Solve { verbose, .. } => solve(&merge_ranges(&id_ranges), rule, verbose),
+        Command::Report { format } => {
+            let ranges = report(&id_ranges, &rule.unwrap_or_else(Rule::at_least_twice));
+
+            match format {
+                Format::Table => print!("{}", to_table(&ranges)),
+                Format::Csv => print!("{}", to_csv(&ranges)),
+                Format::Json => println!("{}", to_json(&ranges)),
+            }
+        }
+    }
 }
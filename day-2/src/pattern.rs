@@ -0,0 +1,112 @@
+use crate::Rule;
+
+/// The rule's radix raised to `exponent`
+fn power(rule: &Rule, exponent: u32) -> u128 {
+    u128::from(rule.radix()).pow(exponent)
+}
+
+/// Multiplier repeating a unit of `unit_length` digits up to `length` digits, e.g.
+/// `10101` turning the unit `12` into the ID `121212` in base 10
+fn repeater(unit_length: u32, length: u32, rule: &Rule) -> u128 {
+    (power(rule, length) - 1) / (power(rule, unit_length) - 1)
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Number of digits the rule checks the ID at
+fn checked_digits(id: u64, rule: &Rule) -> u32 {
+    rule.digits(id).max(rule.pad_width().unwrap_or(0))
+}
+
+/// First and last unit of `unit_length` digits whose repetition up to `length` digits
+/// lies within `lower..=upper`. Units only start with a zero if the rule pads IDs to
+/// `length` digits, or if the unit is the single digit of the ID 0.
+fn unit_bounds(
+    lower: u64,
+    upper: u64,
+    unit_length: u32,
+    length: u32,
+    rule: &Rule,
+) -> Option<(u128, u128)> {
+    let repeater = repeater(unit_length, length, rule);
+    let smallest_unit = match rule.pad_width() {
+        Some(width) if width >= length => 0,
+        _ if length == 1 => 0,
+        _ => power(rule, unit_length - 1),
+    };
+    let first = u128::from(lower).div_ceil(repeater).max(smallest_unit);
+    let last = (u128::from(upper) / repeater).min(power(rule, unit_length) - 1);
+
+    (first <= last).then_some((first, last))
+}
+
+/// Sum of the IDs within `lower..=upper` made of a unit of `unit_length` digits
+/// repeated up to `length` digits
+fn sum_repetitions(lower: u64, upper: u64, unit_length: u32, length: u32, rule: &Rule) -> u128 {
+    let Some((first, last)) = unit_bounds(lower, upper, unit_length, length, rule) else {
+        return 0;
+    };
+
+    let count = last - first + 1;
+    let unit_sum = if count % 2 == 0 {
+        count / 2 * (first + last)
+    } else {
+        (first + last) / 2 * count
+    };
+
+    unit_sum * repeater(unit_length, length, rule)
+}
+
+/// Sum of the IDs of `length` digits within `lower..=upper` repeating any of the unit
+/// lengths the rule allows. IDs repeating units of several lengths repeat a unit of
+/// their greatest common divisor, so inclusion-exclusion over the allowed unit lengths
+/// counts each ID once.
+fn sum_length(lower: u64, upper: u64, length: u32, rule: &Rule) -> u128 {
+    let unit_lengths = rule.unit_lengths_for(length);
+    let (mut added, mut removed): (u128, u128) = (0, 0);
+
+    for subset in 1..1u64 << unit_lengths.len() {
+        let unit_length = (0..unit_lengths.len())
+            .filter(|&index| subset & (1 << index) != 0)
+            .map(|index| unit_lengths[index])
+            .fold(0, gcd);
+        let sum = sum_repetitions(lower, upper, unit_length, length, rule);
+
+        if subset.count_ones() % 2 == 1 {
+            added += sum;
+        } else {
+            removed += sum;
+        }
+    }
+
+    added - removed
+}
+
+/// Sums the IDs within `lower..=upper` that follow the rule, in time depending only on
+/// the number of digits of the bounds
+pub fn sum_repeated_ids(lower: u64, upper: u64, rule: &Rule) -> u128 {
+    (checked_digits(lower, rule)..=checked_digits(upper, rule))
+        .map(|length| sum_length(lower, upper, length, rule))
+        .sum()
+}
+
+/// Lists the IDs within `lower..=upper` that follow the rule in ascending order,
+/// building each from its repeating unit
+pub fn repeated_ids(lower: u64, upper: u64, rule: &Rule) -> Vec<u64> {
+    let mut ids: Vec<u64> = vec![];
+
+    for length in checked_digits(lower, rule)..=checked_digits(upper, rule) {
+        for unit_length in rule.unit_lengths_for(length) {
+            if let Some((first, last)) = unit_bounds(lower, upper, unit_length, length, rule) {
+                let repeater = repeater(unit_length, length, rule);
+                ids.extend((first..=last).map(|unit| (unit * repeater) as u64));
+            }
+        }
+    }
+
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
@@ -0,0 +1,160 @@
+use crate::{IdRange, Rule, pattern};
+use common::csv;
+use common::json::{Json, render_lines};
+
+/// An invalid product ID and the repetition that makes it invalid
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidId {
+    pub id: u64,
+    /// The ID written in the rule's radix, as checked
+    pub written: String,
+    /// The shortest repeating unit in the rule's radix, zero-padded if the rule pads IDs
+    pub unit: String,
+    pub repetitions: u32,
+}
+
+/// The invalid product IDs of a single range of the input
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeReport {
+    pub lower: u64,
+    pub upper: u64,
+    /// Invalid IDs in ascending order
+    pub invalid_ids: Vec<InvalidId>,
+    /// Sum of the invalid IDs
+    pub total: u128,
+}
+
+/// Lists the invalid product IDs of every range, in the order of the input
+pub fn report(id_ranges: &[IdRange], rule: &Rule) -> Vec<RangeReport> {
+    id_ranges
+        .iter()
+        .map(|&IdRange { lower, upper }| {
+            let invalid_ids: Vec<InvalidId> = pattern::repeated_ids(lower, upper, rule)
+                .into_iter()
+                .map(|id| {
+                    let written = rule.format(id);
+                    let (unit, repetitions) = rule
+                        .matching_unit(&written)
+                        .expect("Generated IDs follow the rule");
+
+                    InvalidId {
+                        id,
+                        written,
+                        unit,
+                        repetitions,
+                    }
+                })
+                .collect();
+
+            RangeReport {
+                lower,
+                upper,
+                total: invalid_ids
+                    .iter()
+                    .map(|invalid| u128::from(invalid.id))
+                    .sum(),
+                invalid_ids,
+            }
+        })
+        .collect()
+}
+
+/// Renders every range with its total, followed by its invalid IDs and how they are
+/// written if that differs from base 10
+pub fn to_table(ranges: &[RangeReport]) -> String {
+    let mut output = String::new();
+
+    for range in ranges {
+        output.push_str(&format!(
+            "{}-{}: {} invalid IDs, total {}\n",
+            range.lower,
+            range.upper,
+            range.invalid_ids.len(),
+            range.total
+        ));
+
+        for invalid in &range.invalid_ids {
+            let id = invalid.id.to_string();
+            let written = if invalid.written == id {
+                id
+            } else {
+                format!("{} ({})", id, invalid.written)
+            };
+
+            output.push_str(&format!(
+                "    {} = {} x {}\n",
+                written, invalid.unit, invalid.repetitions
+            ));
+        }
+    }
+
+    output
+}
+
+/// One `id` row per invalid ID, followed by a `total` row with the count and sum of
+/// the range's invalid IDs, which every range has even without any
+pub fn to_csv(ranges: &[RangeReport]) -> String {
+    let mut output = String::from("lower,upper,kind,id,written,unit,repetitions,count,total\n");
+
+    for range in ranges {
+        for invalid in &range.invalid_ids {
+            output.push_str(&csv::row(&[
+                range.lower.to_string(),
+                range.upper.to_string(),
+                String::from("id"),
+                invalid.id.to_string(),
+                invalid.written.clone(),
+                invalid.unit.clone(),
+                invalid.repetitions.to_string(),
+                String::new(),
+                String::new(),
+            ]));
+        }
+
+        output.push_str(&csv::row(&[
+            range.lower.to_string(),
+            range.upper.to_string(),
+            String::from("total"),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            range.invalid_ids.len().to_string(),
+            range.total.to_string(),
+        ]));
+    }
+
+    output
+}
+
+/// One record per range, holding its total and its invalid IDs. IDs, bounds and
+/// totals are strings, since they may exceed the integers JSON readers keep exactly.
+pub fn to_json(ranges: &[RangeReport]) -> String {
+    let records: Vec<Json> = ranges
+        .iter()
+        .map(|range| {
+            let invalid_ids: Vec<Json> = range
+                .invalid_ids
+                .iter()
+                .map(|invalid| {
+                    Json::object([
+                        ("id", Json::from(invalid.id.to_string())),
+                        ("written", Json::from(invalid.written.as_str())),
+                        ("unit", Json::from(invalid.unit.as_str())),
+                        ("repetitions", Json::from(invalid.repetitions)),
+                    ])
+                })
+                .collect();
+
+            Json::object([
+                ("lower", Json::from(range.lower.to_string())),
+                ("upper", Json::from(range.upper.to_string())),
+                ("count", Json::from(range.invalid_ids.len())),
+                ("total", Json::from(range.total.to_string())),
+                ("invalid_ids", Json::from(invalid_ids)),
+            ])
+        })
+        .collect();
+
+    render_lines(&records)
+}
@@ -0,0 +1,212 @@
+/// Bases IDs can be written in, limited by the digits of [`char::from_digit`]
+pub const RADIXES: std::ops::RangeInclusive<u32> = 2..=36;
+
+/// Which product IDs count as a repeated sequence of digits. The fields are only set
+/// through checked methods, so that every rule can be applied to any ID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    /// Fewest times the unit has to repeat, at least 1
+    min_repetitions: u32,
+    /// Most times the unit may repeat, any number if `None`
+    max_repetitions: Option<u32>,
+    /// Lengths the repeating unit may have, any length if `None`
+    unit_lengths: Option<Vec<u32>>,
+    /// Number of digits IDs are zero-padded to before checking them, so that their
+    /// unit may start with a zero. `None` checks the IDs as written. At most
+    /// [`Rule::max_digits`].
+    pad_width: Option<u32>,
+    /// Base the IDs are written in for checking them, within [`RADIXES`]
+    radix: u32,
+}
+
+impl Rule {
+    /// A unit of any length repeated from `min_repetitions` up to `max_repetitions`
+    /// times, or any number of times if `None`
+    pub fn new(min_repetitions: u32, max_repetitions: Option<u32>) -> Result<Self, String> {
+        if min_repetitions == 0 {
+            return Err(String::from("The unit has to repeat at least once"));
+        }
+
+        if let Some(max) = max_repetitions
+            && max < min_repetitions
+        {
+            return Err(format!(
+                "Maximum of {} repetitions is below the minimum of {}",
+                max, min_repetitions
+            ));
+        }
+
+        Ok(Rule {
+            min_repetitions,
+            max_repetitions,
+            ..Rule::at_least_twice()
+        })
+    }
+
+    /// A unit repeated exactly twice, the puzzle's first part
+    pub fn exactly_twice() -> Self {
+        Rule {
+            min_repetitions: 2,
+            max_repetitions: Some(2),
+            unit_lengths: None,
+            pad_width: None,
+            radix: 10,
+        }
+    }
+
+    /// A unit repeated at least twice, the puzzle's second part
+    pub fn at_least_twice() -> Self {
+        Rule {
+            min_repetitions: 2,
+            max_repetitions: None,
+            unit_lengths: None,
+            pad_width: None,
+            radix: 10,
+        }
+    }
+
+    /// Only allows units of the given lengths
+    pub fn with_unit_lengths(mut self, unit_lengths: Vec<u32>) -> Result<Self, String> {
+        if unit_lengths.contains(&0) {
+            return Err(String::from("Units need at least one digit"));
+        }
+
+        self.unit_lengths = Some(unit_lengths);
+        Ok(self)
+    }
+
+    /// Zero-pads IDs to `width` digits before checking them
+    pub fn with_pad_width(mut self, width: u32) -> Result<Self, String> {
+        if width > self.max_digits() {
+            return Err(format!(
+                "Pad width is at most {} in base {}, got {}",
+                self.max_digits(),
+                self.radix,
+                width
+            ));
+        }
+
+        self.pad_width = Some(width);
+        Ok(self)
+    }
+
+    /// Checks IDs written in the given base. Set it before a pad width, which is
+    /// limited by the digits of the largest ID in that base.
+    pub fn with_radix(mut self, radix: u32) -> Result<Self, String> {
+        if !RADIXES.contains(&radix) {
+            return Err(format!(
+                "Radix is from {} to {}, got {}",
+                RADIXES.start(),
+                RADIXES.end(),
+                radix
+            ));
+        }
+
+        self.radix = radix;
+        if let Some(width) = self.pad_width {
+            return self.with_pad_width(width);
+        }
+
+        Ok(self)
+    }
+
+    pub fn min_repetitions(&self) -> u32 {
+        self.min_repetitions
+    }
+
+    pub fn max_repetitions(&self) -> Option<u32> {
+        self.max_repetitions
+    }
+
+    pub fn unit_lengths(&self) -> Option<&[u32]> {
+        self.unit_lengths.as_deref()
+    }
+
+    pub fn pad_width(&self) -> Option<u32> {
+        self.pad_width
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Number of digits of the ID in the rule's radix
+    pub fn digits(&self, id: u64) -> u32 {
+        id.checked_ilog(u64::from(self.radix)).unwrap_or(0) + 1
+    }
+
+    /// Number of digits of the largest ID in the rule's radix
+    pub fn max_digits(&self) -> u32 {
+        self.digits(u64::MAX)
+    }
+
+    /// Writes the ID in the rule's radix, with lowercase letters for digits above 9
+    pub fn format(&self, id: u64) -> String {
+        let radix = u64::from(self.radix);
+        let mut digits: Vec<char> = vec![];
+        let mut rest = id;
+
+        loop {
+            digits.push(char::from_digit((rest % radix) as u32, self.radix).unwrap());
+            rest /= radix;
+
+            if rest == 0 {
+                break;
+            }
+        }
+
+        digits.iter().rev().collect()
+    }
+
+    /// Whether an ID of `length` digits may repeat a unit of `unit_length` digits
+    pub fn allows(&self, unit_length: u32, length: u32) -> bool {
+        if unit_length == 0 || !length.is_multiple_of(unit_length) {
+            return false;
+        }
+
+        let repetitions = length / unit_length;
+
+        repetitions >= self.min_repetitions
+            && self.max_repetitions.is_none_or(|max| repetitions <= max)
+            && self
+                .unit_lengths
+                .as_ref()
+                .is_none_or(|unit_lengths| unit_lengths.contains(&unit_length))
+    }
+
+    /// Unit lengths the rule allows for an ID of `length` digits, in ascending order
+    pub fn unit_lengths_for(&self, length: u32) -> Vec<u32> {
+        (1..=length)
+            .filter(|&unit_length| self.allows(unit_length, length))
+            .collect()
+    }
+
+    /// Whether the ID follows the rule
+    pub fn matches_id(&self, id: u64) -> bool {
+        self.matches(&self.format(id))
+    }
+
+    /// Whether the product ID, written in the rule's radix without leading zeros,
+    /// follows the rule
+    pub fn matches(&self, product_id: &str) -> bool {
+        self.matching_unit(product_id).is_some()
+    }
+
+    /// The shortest unit the product ID repeats under the rule, zero-padded if the rule
+    /// pads IDs, along with how often it repeats
+    pub fn matching_unit(&self, product_id: &str) -> Option<(String, u32)> {
+        let width = self.pad_width.unwrap_or(0) as usize;
+        let product_id = format!("{:0>width$}", product_id);
+        let length = product_id.len() as u32;
+
+        self.unit_lengths_for(length)
+            .into_iter()
+            .find_map(|unit_length| {
+                let unit = &product_id[..unit_length as usize];
+                let repetitions = length / unit_length;
+
+                (product_id == unit.repeat(repetitions as usize))
+                    .then(|| (String::from(unit), repetitions))
+            })
+    }
+}
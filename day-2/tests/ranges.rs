@@ -0,0 +1,84 @@
+use day_2::{IdRange, merge_ranges, parse_id_ranges};
+
+fn ranges(bounds: &[(u64, u64)]) -> Vec<IdRange> {
+    bounds
+        .iter()
+        .map(|&(lower, upper)| IdRange { lower, upper })
+        .collect()
+}
+
+#[test]
+fn parses_ranges_separated_by_commas_and_newlines() {
+    assert_eq!(
+        parse_id_ranges("11-22,95-115\n998-1012").unwrap(),
+        ranges(&[(11, 22), (95, 115), (998, 1012)])
+    );
+}
+
+#[test]
+fn ignores_whitespace_and_blank_entries() {
+    assert_eq!(
+        parse_id_ranges(" 11 - 22 ,,\n\n  95-115,\r\n,998-1012,\n").unwrap(),
+        ranges(&[(11, 22), (95, 115), (998, 1012)])
+    );
+    assert_eq!(parse_id_ranges("").unwrap(), []);
+    assert_eq!(parse_id_ranges(" ,\n, ").unwrap(), []);
+}
+
+#[test]
+fn swaps_reversed_bounds() {
+    assert_eq!(
+        parse_id_ranges("22-11,7-7").unwrap(),
+        ranges(&[(11, 22), (7, 7)])
+    );
+}
+
+#[test]
+fn locates_errors() {
+    let cases = [
+        ("5-", 1, 1, "5-", "Missing upper bound"),
+        ("-5", 1, 1, "-5", "Missing lower bound"),
+        ("abc", 1, 1, "abc", "Invalid lower bound"),
+        ("1-2,  5- ", 1, 7, "5-", "Missing upper bound"),
+        ("1-2,\n3-4, 7-x", 2, 8, "x", "Invalid upper bound"),
+        ("1-2\n\n  -9,3-4", 3, 3, "-9", "Missing lower bound"),
+        ("1-2,3", 1, 5, "3", "Missing upper bound"),
+    ];
+
+    for (input, line, column, text, message) in cases {
+        let error = parse_id_ranges(input).unwrap_err();
+
+        assert_eq!(
+            (
+                error.line,
+                error.column,
+                error.text.as_str(),
+                error.message.as_str()
+            ),
+            (line, column, text, message),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn merges_overlapping_and_sorts() {
+    assert_eq!(
+        merge_ranges(&ranges(&[(15, 30), (10, 20), (40, 50), (31, 35)])),
+        ranges(&[(10, 30), (31, 35), (40, 50)])
+    );
+}
+
+#[test]
+fn merges_ranges_sharing_a_bound_and_contained_ones() {
+    assert_eq!(
+        merge_ranges(&ranges(&[(10, 20), (20, 25), (12, 14), (1, 100)])),
+        ranges(&[(1, 100)])
+    );
+    assert_eq!(
+        merge_ranges(&ranges(&[(5, 5), (5, 5), (6, 6)])),
+        ranges(&[(5, 5), (6, 6)])
+    );
+    assert_eq!(merge_ranges(&[]), []);
+}
@@ -0,0 +1,141 @@
+use day_2::report::{InvalidId, report, to_csv, to_json, to_table};
+use day_2::{IdRange, Rule};
+
+fn id_ranges(bounds: &[(u64, u64)]) -> Vec<IdRange> {
+    bounds
+        .iter()
+        .map(|&(lower, upper)| IdRange { lower, upper })
+        .collect()
+}
+
+/// The IDs, units and repetitions of every range of the report
+fn listed(id_ranges: &[IdRange], rule: &Rule) -> Vec<Vec<(u64, String, u32)>> {
+    report(id_ranges, rule)
+        .into_iter()
+        .map(|range| {
+            range
+                .invalid_ids
+                .into_iter()
+                .map(|invalid| (invalid.id, invalid.unit, invalid.repetitions))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn keeps_overlapping_ranges_in_input_order() {
+    let id_ranges = id_ranges(&[(20, 40), (10, 30), (23, 30)]);
+    let ranges = report(&id_ranges, &Rule::at_least_twice());
+
+    let bounds: Vec<(u64, u64)> = ranges
+        .iter()
+        .map(|range| (range.lower, range.upper))
+        .collect();
+    assert_eq!(bounds, [(20, 40), (10, 30), (23, 30)]);
+
+    let ids: Vec<Vec<u64>> = ranges
+        .iter()
+        .map(|range| range.invalid_ids.iter().map(|invalid| invalid.id).collect())
+        .collect();
+    assert_eq!(ids, [vec![22, 33], vec![11, 22], vec![]]);
+
+    let totals: Vec<u128> = ranges.iter().map(|range| range.total).collect();
+    assert_eq!(totals, [55, 33, 0]);
+}
+
+#[test]
+fn shortest_unit_and_repetitions() {
+    let id_ranges = id_ranges(&[(100, 120), (1100, 1215)]);
+
+    assert_eq!(
+        listed(&id_ranges, &Rule::at_least_twice()),
+        [
+            vec![(111, String::from("1"), 3)],
+            vec![(1111, String::from("1"), 4), (1212, String::from("12"), 2)],
+        ]
+    );
+    assert_eq!(
+        listed(&id_ranges, &Rule::exactly_twice()),
+        [
+            vec![],
+            vec![(1111, String::from("11"), 2), (1212, String::from("12"), 2)]
+        ]
+    );
+}
+
+#[test]
+fn written_in_the_rule_radix() {
+    let hex = Rule::exactly_twice().with_radix(16).unwrap();
+    let ranges = report(&id_ranges(&[(0x11, 0x22)]), &hex);
+
+    assert_eq!(
+        ranges[0].invalid_ids,
+        [
+            InvalidId {
+                id: 0x11,
+                written: String::from("11"),
+                unit: String::from("1"),
+                repetitions: 2,
+            },
+            InvalidId {
+                id: 0x22,
+                written: String::from("22"),
+                unit: String::from("2"),
+                repetitions: 2,
+            },
+        ]
+    );
+    assert_eq!(ranges[0].total, 0x33);
+    assert_eq!(
+        to_table(&ranges),
+        "17-34: 2 invalid IDs, total 51\n    17 (11) = 1 x 2\n    34 (22) = 2 x 2\n"
+    );
+}
+
+#[test]
+fn table() {
+    let ranges = report(&id_ranges(&[(95, 115), (23, 30)]), &Rule::at_least_twice());
+
+    assert_eq!(
+        to_table(&ranges),
+        "95-115: 2 invalid IDs, total 210\n\
+         \x20   99 = 9 x 2\n\
+         \x20   111 = 1 x 3\n\
+         23-30: 0 invalid IDs, total 0\n"
+    );
+}
+
+#[test]
+fn csv_has_a_total_row_per_range() {
+    let ranges = report(&id_ranges(&[(95, 115), (23, 30)]), &Rule::at_least_twice());
+
+    assert_eq!(
+        to_csv(&ranges),
+        "lower,upper,kind,id,written,unit,repetitions,count,total\n\
+         95,115,id,99,99,9,2,,\n\
+         95,115,id,111,111,1,3,,\n\
+         95,115,total,,,,,2,210\n\
+         23,30,total,,,,,0,0\n"
+    );
+}
+
+#[test]
+fn json_writes_large_numbers_as_strings() {
+    let ranges = report(&id_ranges(&[(95, 115), (23, 30)]), &Rule::at_least_twice());
+
+    assert_eq!(
+        to_json(&ranges),
+        "[\n  \
+         {\"lower\":\"95\",\"upper\":\"115\",\"count\":2,\"total\":\"210\",\"invalid_ids\":[\
+         {\"id\":\"99\",\"written\":\"99\",\"unit\":\"9\",\"repetitions\":2},\
+         {\"id\":\"111\",\"written\":\"111\",\"unit\":\"1\",\"repetitions\":3}]},\n  \
+         {\"lower\":\"23\",\"upper\":\"30\",\"count\":0,\"total\":\"0\",\"invalid_ids\":[]}\n\
+         ]"
+    );
+
+    let id = 100_000_001_100_000_001;
+    let ranges = report(&id_ranges(&[(id, id)]), &Rule::at_least_twice());
+
+    assert!(to_json(&ranges).contains(&format!("\"id\":\"{}\"", id)));
+    assert!(to_json(&ranges).contains(&format!("\"total\":\"{}\"", id)));
+}
@@ -0,0 +1,238 @@
+use day_2::pattern::{repeated_ids, sum_repeated_ids};
+use day_2::{IdRange, Rule, calculate_code, is_repeated_digit_pattern};
+
+/// xorshift64, so that every run checks the same ranges
+fn random_ranges(seed: u64, count: usize) -> Vec<(u64, u64)> {
+    let mut state = seed;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..count)
+        .map(|_| {
+            let scale = [100, 10_000, 1_000_000, 100_000_000][(next() % 4) as usize];
+            let lower = next() % scale;
+            (lower, lower + next() % 5_000)
+        })
+        .collect()
+}
+
+fn brute_force(lower: u64, upper: u64, rule: &Rule) -> Vec<u64> {
+    (lower..=upper).filter(|&id| rule.matches_id(id)).collect()
+}
+
+#[test]
+fn exactly_twice_is_the_first_mode() {
+    let rule = Rule::exactly_twice();
+
+    for id in 0..200_000u64 {
+        let product_id = id.to_string();
+        assert_eq!(
+            rule.matches(&product_id),
+            is_repeated_digit_pattern(&product_id, true),
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn at_least_twice_is_the_second_mode() {
+    let rule = Rule::at_least_twice();
+
+    for id in 0..200_000u64 {
+        let product_id = id.to_string();
+        assert_eq!(
+            rule.matches(&product_id),
+            is_repeated_digit_pattern(&product_id, false),
+            "{}",
+            id
+        );
+    }
+}
+
+#[test]
+fn both_modes_sum_like_the_scan() {
+    for (lower, upper) in random_ranges(0xDA7_0002, 200) {
+        for max_twice in [true, false] {
+            let rule = match max_twice {
+                true => Rule::exactly_twice(),
+                false => Rule::at_least_twice(),
+            };
+            let expected: u128 = (lower..=upper)
+                .filter(|id| is_repeated_digit_pattern(&id.to_string(), max_twice))
+                .map(u128::from)
+                .sum();
+
+            assert_eq!(
+                calculate_code(&[IdRange { lower, upper }], &rule),
+                expected,
+                "{}-{} with max_twice {}",
+                lower,
+                upper,
+                max_twice
+            );
+        }
+    }
+}
+
+#[test]
+fn custom_rules() {
+    let three_times = Rule::new(3, Some(3)).unwrap();
+    assert!(three_times.matches("123123123"));
+    assert!(three_times.matches("777"));
+    assert!(!three_times.matches("1212"));
+    assert!(three_times.matches("777777"));
+    assert!(!three_times.matches("7777"));
+
+    let units_of_two = Rule::at_least_twice().with_unit_lengths(vec![2]).unwrap();
+    assert!(units_of_two.matches("121212"));
+    assert!(units_of_two.matches("1111"));
+    assert!(!units_of_two.matches("111"));
+    assert!(!units_of_two.matches("123123"));
+
+    let padded = Rule::exactly_twice().with_pad_width(4).unwrap();
+    assert!(padded.matches("101"));
+    assert!(padded.matches("0"));
+    assert!(!padded.matches("11"));
+    assert!(padded.matches("123123"));
+}
+
+#[test]
+fn other_radixes() {
+    let hex = Rule::exactly_twice().with_radix(16).unwrap();
+    assert_eq!(hex.format(0xabab), "abab");
+    assert_eq!(hex.digits(0xabab), 4);
+    assert_eq!(hex.max_digits(), 16);
+    assert!(hex.matches_id(0xabab));
+    assert!(!hex.matches_id(0xaba));
+    assert!(!hex.matches_id(1111));
+
+    let binary = Rule::at_least_twice().with_radix(2).unwrap();
+    assert_eq!(binary.format(0), "0");
+    assert_eq!(binary.max_digits(), 64);
+    assert!(binary.matches_id(0b101101));
+    assert!(binary.matches_id(0b111));
+    assert!(!binary.matches_id(0b110));
+
+    for radix in 2..=36 {
+        let rule = Rule::at_least_twice().with_radix(radix).unwrap();
+        assert_eq!(rule.format(u64::MAX).len() as u32, rule.max_digits());
+
+        for id in 0..5_000u64 {
+            let product_id = rule.format(id);
+            assert_eq!(
+                u64::from_str_radix(&product_id, radix).unwrap(),
+                id,
+                "{} in base {}",
+                id,
+                radix
+            );
+            assert_eq!(
+                rule.matches(&product_id),
+                is_repeated_digit_pattern(&product_id, false),
+                "{} in base {}",
+                id,
+                radix
+            );
+        }
+    }
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    assert!(Rule::new(0, None).is_err());
+    assert!(Rule::new(3, Some(2)).is_err());
+    assert!(
+        Rule::at_least_twice()
+            .with_unit_lengths(vec![0, 2])
+            .is_err()
+    );
+
+    for radix in [0, 1, 37] {
+        assert!(
+            Rule::at_least_twice().with_radix(radix).is_err(),
+            "{}",
+            radix
+        );
+    }
+
+    assert!(Rule::at_least_twice().with_pad_width(20).is_ok());
+    assert!(Rule::at_least_twice().with_pad_width(21).is_err());
+
+    let binary = Rule::at_least_twice().with_radix(2).unwrap();
+    assert!(binary.clone().with_pad_width(64).is_ok());
+    assert!(binary.with_pad_width(65).is_err());
+
+    // The pad width is checked again against a radix with fewer digits
+    let padded = Rule::at_least_twice().with_pad_width(20).unwrap();
+    assert!(padded.clone().with_radix(16).is_err());
+    assert!(padded.with_radix(8).is_ok());
+}
+
+#[test]
+fn custom_rules_sum_like_the_scan() {
+    let rules = [
+        Rule::new(3, Some(4)),
+        Rule::new(1, Some(1)),
+        Rule::new(1, None),
+        Rule::at_least_twice().with_unit_lengths(vec![1, 3]),
+        Rule::at_least_twice().with_pad_width(6),
+        Rule::new(2, Some(3))
+            .and_then(|rule| rule.with_unit_lengths(vec![2, 3, 4]))
+            .and_then(|rule| rule.with_pad_width(8)),
+        Rule::at_least_twice().with_radix(2),
+        Rule::exactly_twice().with_radix(16),
+        Rule::at_least_twice()
+            .with_radix(36)
+            .and_then(|rule| rule.with_pad_width(4)),
+        Rule::at_least_twice()
+            .with_radix(3)
+            .and_then(|rule| rule.with_unit_lengths(vec![2, 3])),
+    ]
+    .map(Result::unwrap);
+
+    let mut ranges = random_ranges(0xDA7_0003, 150);
+    ranges.extend([(0, 0), (0, 9), (0, 1_000)]);
+
+    for rule in &rules {
+        for &(lower, upper) in &ranges {
+            let expected = brute_force(lower, upper, rule);
+
+            assert_eq!(repeated_ids(lower, upper, rule), expected, "{:?}", rule);
+            assert_eq!(
+                sum_repeated_ids(lower, upper, rule),
+                expected.iter().copied().map(u128::from).sum(),
+                "{}-{} with {:?}",
+                lower,
+                upper,
+                rule
+            );
+            assert_eq!(
+                calculate_code(&[IdRange { lower, upper }], rule),
+                expected.iter().copied().map(u128::from).sum(),
+                "{}-{} with {:?}",
+                lower,
+                upper,
+                rule
+            );
+        }
+    }
+}
+
+#[test]
+fn zero_is_a_single_digit_id() {
+    let every_id = Rule::new(1, None).unwrap();
+
+    assert!(every_id.matches_id(0));
+    assert_eq!(repeated_ids(0, 3, &every_id), [0, 1, 2, 3]);
+    assert_eq!(sum_repeated_ids(0, 3, &every_id), 6);
+
+    let twice = Rule::at_least_twice();
+
+    assert!(!twice.matches_id(0));
+    assert_eq!(repeated_ids(0, 11, &twice), [11]);
+}
@@ -0,0 +1,124 @@
+use common::{ParseError, Solution};
+
+/// Largest joltage obtainable by turning on `battery_count` batteries of the bank,
+/// read as the number formed by their digits in order
+pub fn get_highest_bank_joltage(bank: &str, mut battery_count: u16) -> u64 {
+    let mut highest_joltage = 0;
+    let bank_size = bank.len();
+    let batteries = bank.chars().collect::<Vec<_>>();
+
+    // Safety-net to prevent issues
+    if battery_count == 0 {
+        return 0;
+    }
+
+    if battery_count as usize > bank_size {
+        battery_count = bank_size as u16
+    }
+
+    match battery_count {
+        2 => {
+            for i in 0..bank_size {
+                let current_digit = batteries[i];
+
+                for digit in bank[i + 1..].chars() {
+                    let joltage = format!("{}{}", current_digit, digit).parse().unwrap();
+
+                    if joltage > highest_joltage {
+                        highest_joltage = joltage
+                    }
+                }
+            }
+        }
+        _ => {
+            // Use an algorithm to choose the lexicographically
+            // Largest subsequence of length `battery_count` while preserving order
+            let battery_count = battery_count as usize;
+
+            let mut to_remove = bank_size.saturating_sub(battery_count);
+            let mut stack: Vec<char> = Vec::with_capacity(bank_size);
+
+            for &ch in &batteries {
+                while to_remove > 0 && !stack.is_empty() && *stack.last().unwrap() < ch {
+                    stack.pop();
+                    to_remove -= 1;
+                }
+                stack.push(ch);
+            }
+
+            // If we still need to remove characters, pop from the end
+            while to_remove > 0 {
+                stack.pop();
+                to_remove -= 1;
+            }
+
+            // Take the first battery_count characters from the stack as the chosen subsequence
+            let selected: String = stack.iter().take(battery_count).cloned().collect();
+
+            highest_joltage = selected.parse().unwrap();
+        }
+    }
+
+    highest_joltage
+}
+
+/// Parses one bank of battery joltage digits per line, skipping blank lines
+pub fn parse_banks(banks_of_batteries: &str) -> Result<Vec<String>, ParseError> {
+    let mut banks: Vec<String> = vec![];
+
+    for (index, source_line) in banks_of_batteries.lines().enumerate() {
+        let bank = source_line.trim();
+        if bank.is_empty() {
+            continue;
+        }
+
+        if let Some((position, battery)) = bank
+            .char_indices()
+            .find(|(_, battery)| !battery.is_ascii_digit())
+        {
+            let indent = source_line.len() - source_line.trim_start().len();
+            return Err(ParseError::at(
+                index + 1,
+                source_line,
+                indent + position,
+                battery.len_utf8(),
+                "Invalid battery joltage",
+            ));
+        }
+
+        banks.push(String::from(bank));
+    }
+
+    Ok(banks)
+}
+
+/// Sums the highest joltage of every bank
+pub fn get_total_joltage(banks: &[String], battery_count: u16) -> u64 {
+    banks
+        .iter()
+        .map(|bank| get_highest_bank_joltage(bank, battery_count))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = "banks_of_batteries.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_banks(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Self::Answer {
+        get_total_joltage(banks, 2)
+    }
+
+    fn part2(banks: &Self::Input) -> Self::Answer {
+        get_total_joltage(banks, 12)
+    }
+}
@@ -0,0 +1,93 @@
+use common::grid::{Cell, Grid, Neighbourhood};
+use common::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    PaperRoll,
+    Empty,
+}
+
+impl Cell for Tile {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '@' => Some(Tile::PaperRoll),
+            '.' => Some(Tile::Empty),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::PaperRoll => '@',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+/// Positions (row, column) of the paper rolls with fewer than 4 neighbouring rolls
+pub fn get_accessible_paper_rolls(grid: &Grid<Tile>) -> Vec<(usize, usize)> {
+    grid.positions()
+        .filter(|&position| grid[position] == Tile::PaperRoll)
+        .filter(|&(row, col)| {
+            let count = grid
+                .neighbours(row, col, Neighbourhood::Eight)
+                .filter(|&neighbor| grid[neighbor] == Tile::PaperRoll)
+                .count();
+
+            count < 4
+        })
+        .collect()
+}
+
+/// Replaces the paper rolls at the given positions (row, column) with empty space
+pub fn remove_paper_rolls(grid: &mut Grid<Tile>, positions: &[(usize, usize)]) {
+    for &position in positions {
+        grid[position] = Tile::Empty;
+    }
+}
+
+/// Parses the diagram of paper rolls (`@`) and empty space (`.`)
+pub fn parse_diagram(diagram: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(diagram)
+}
+
+/// Removes accessible paper rolls until none are left, returning how many were removed
+pub fn count_removable_paper_rolls(grid: &mut Grid<Tile>) -> usize {
+    let mut removed_paper_rolls = 0;
+
+    loop {
+        let accessible = get_accessible_paper_rolls(grid);
+
+        if accessible.is_empty() {
+            break;
+        }
+
+        removed_paper_rolls += accessible.len();
+        remove_paper_rolls(grid, &accessible);
+    }
+
+    removed_paper_rolls
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = "diagram.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Grid<Tile>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_diagram(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer {
+        get_accessible_paper_rolls(grid).len()
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer {
+        count_removable_paper_rolls(&mut grid.clone())
+    }
+}
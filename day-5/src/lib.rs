@@ -0,0 +1,140 @@
+use common::{ParseError, Solution};
+
+/// Inclusive range of fresh ingredient IDs
+#[derive(Debug, Clone)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+}
+
+/// Fresh ingredient ID ranges followed by the available ingredient IDs
+pub struct Database {
+    pub fresh_ingredient_id_ranges: Vec<Range>,
+    pub ingredient_ids: Vec<i64>,
+}
+
+/// Parses the ID starting at byte `offset` of the line
+fn parse_id(
+    line_number: usize,
+    source_line: &str,
+    offset: usize,
+    id: &str,
+) -> Result<i64, ParseError> {
+    id.parse().map_err(|_| {
+        ParseError::at(
+            line_number,
+            source_line,
+            offset,
+            id.len(),
+            "Invalid ingredient ID",
+        )
+    })
+}
+
+/// Parses the ranges and ingredient IDs, which are separated by a blank line
+pub fn parse_database(ingredients_db: &str) -> Result<Database, ParseError> {
+    let mut lines = ingredients_db.lines().enumerate();
+    let mut fresh_ingredient_id_ranges: Vec<Range> = vec![];
+    let mut found_separator = false;
+
+    // Fresh ranges come first, separated from the ingredient IDs by a blank line
+    for (index, range_line) in lines.by_ref() {
+        if range_line.is_empty() {
+            found_separator = true;
+            break;
+        }
+
+        let Some((start, end)) = range_line.split_once("-") else {
+            return Err(ParseError::at_line(
+                index + 1,
+                range_line,
+                "Expected a range like 3-5",
+            ));
+        };
+
+        fresh_ingredient_id_ranges.push(Range {
+            start: parse_id(index + 1, range_line, 0, start)?,
+            end: parse_id(index + 1, range_line, start.len() + 1, end)?,
+        });
+    }
+
+    if !found_separator {
+        return Err(ParseError::empty("No ingredients found!"));
+    }
+
+    let mut ingredient_ids: Vec<i64> = vec![];
+    for (index, id_line) in lines {
+        if !id_line.is_empty() {
+            ingredient_ids.push(parse_id(index + 1, id_line, 0, id_line)?);
+        }
+    }
+
+    Ok(Database {
+        fresh_ingredient_id_ranges,
+        ingredient_ids,
+    })
+}
+
+/// Whether the ingredient falls into any of the fresh ranges
+pub fn is_ingredient_fresh(ingredient_id: &i64, fresh_ingredient_id_ranges: &[Range]) -> bool {
+    fresh_ingredient_id_ranges
+        .iter()
+        .any(|range| *ingredient_id >= range.start && *ingredient_id <= range.end)
+}
+
+/// Number of distinct ingredient IDs covered by the fresh ranges
+pub fn get_expected_fresh_ingredients_count(fresh_ingredient_id_ranges: &[Range]) -> i64 {
+    let mut ranges = fresh_ingredient_id_ranges.to_vec();
+
+    // Sort by start and merge overlaps
+    ranges.sort_unstable_by_key(|r| r.start);
+
+    let mut unique_ranges: Vec<Range> = Vec::new();
+    for range in ranges {
+        if let Some(last) = unique_ranges.last_mut() {
+            if range.start <= last.end + 1 {
+                last.end = last.end.max(range.end); // Merge
+            } else {
+                unique_ranges.push(range);
+            }
+        } else {
+            unique_ranges.push(range);
+        }
+    }
+
+    unique_ranges.iter().map(|r| r.end - r.start + 1).sum()
+}
+
+/// Number of available ingredients that are fresh
+pub fn count_available_fresh_ingredients(database: &Database) -> i64 {
+    database
+        .ingredient_ids
+        .iter()
+        .filter(|ingredient_id| {
+            is_ingredient_fresh(ingredient_id, &database.fresh_ingredient_id_ranges)
+        })
+        .count() as i64
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = "ingredients_db.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Database;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_database(input)
+    }
+
+    fn part1(database: &Self::Input) -> Self::Answer {
+        count_available_fresh_ingredients(database)
+    }
+
+    fn part2(database: &Self::Input) -> Self::Answer {
+        get_expected_fresh_ingredients_count(&database.fresh_ingredient_id_ranges)
+    }
+}
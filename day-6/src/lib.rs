@@ -0,0 +1,249 @@
+use common::{ParseError, Solution};
+
+/// Operands of every problem alongside the operation applied to them
+pub type Problems = (Vec<Vec<u64>>, Vec<String>);
+
+/// Splits the row at whitespace like `split_whitespace`, along with the byte offset
+/// of every word
+fn words(row: &str) -> Vec<(usize, &str)> {
+    let mut words = vec![];
+    let mut word_start: Option<usize> = None;
+
+    for (index, char) in row.char_indices() {
+        match (char.is_whitespace(), word_start) {
+            (false, None) => word_start = Some(index),
+            (true, Some(start)) => {
+                words.push((start, &row[start..index]));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(start) = word_start {
+        words.push((start, &row[start..]));
+    }
+
+    words
+}
+
+fn parse_number_row(line_number: usize, row: &str) -> Result<Vec<u64>, ParseError> {
+    words(row)
+        .into_iter()
+        .map(|(offset, number)| {
+            number.parse().map_err(|_| {
+                ParseError::at(line_number, row, offset, number.len(), "Invalid number")
+            })
+        })
+        .collect()
+}
+
+fn parse_operation_row(row: &str) -> Vec<String> {
+    row.split_whitespace().map(String::from).collect()
+}
+
+/// Parse the math problems formatted in rows of whitespace-separated numbers,
+pub fn split_whitespace_columns(math_problems: &str) -> Result<Problems, ParseError> {
+    let rows: Vec<&str> = math_problems.lines().collect();
+    let Some((&operations_row, number_rows)) = rows.split_last() else {
+        return Err(ParseError::empty("No math problems found"));
+    };
+    let operations = parse_operation_row(operations_row);
+
+    let columns = if number_rows.is_empty() {
+        0
+    } else {
+        parse_number_row(1, number_rows[0])?.len()
+    };
+
+    let mut problems: Vec<Vec<u64>> = vec![Vec::new(); columns];
+
+    for (index, row) in number_rows.iter().enumerate() {
+        let numbers = parse_number_row(index + 1, row)?;
+
+        if numbers.len() != columns {
+            return Err(ParseError::at_line(
+                index + 1,
+                row,
+                format!("Expected {} numbers, found {}", columns, numbers.len()),
+            ));
+        }
+
+        for (index, &number) in numbers.iter().enumerate() {
+            problems[index].push(number);
+        }
+    }
+
+    if operations.len() != columns {
+        return Err(ParseError::at_line(
+            rows.len(),
+            operations_row,
+            format!(
+                "Expected {} operations, found {}",
+                columns,
+                operations.len()
+            ),
+        ));
+    }
+
+    Ok((problems, operations))
+}
+
+/// Parse the cephalopod-style fixed-width digit columns (right-to-left)
+pub fn split_digit_columns(math_problems: &str) -> Result<Problems, ParseError> {
+    let rows: Vec<&str> = math_problems.lines().collect();
+    let Some((&operations_row, number_rows)) = rows.split_last() else {
+        return Err(ParseError::empty("No math problems found"));
+    };
+
+    // Pad rows to the same width using bytes for fast indexed access
+    let max_row_len = number_rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let padded_number_rows: Vec<Vec<u8>> = number_rows
+        .iter()
+        .map(|row| {
+            let mut byte = row.as_bytes().to_vec();
+            byte.resize(max_row_len, b' ');
+            byte
+        })
+        .collect();
+
+    // Separator columns are columns where every row has a space
+    let mut is_separator = vec![false; max_row_len];
+    for i in 0..max_row_len {
+        is_separator[i] = padded_number_rows.iter().all(|row| row[i] == b' ');
+    }
+
+    // Group contiguous non-separator columns
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+
+    while i < max_row_len {
+        if is_separator[i] {
+            i += 1;
+            continue;
+        }
+        let start = i;
+
+        while i < max_row_len && !is_separator[i] {
+            i += 1;
+        }
+        groups.push((start, i - 1));
+    }
+
+    // Prepare padded operations row
+    let mut operations_bytes = operations_row.as_bytes().to_vec();
+    operations_bytes.resize(max_row_len, b' ');
+
+    let mut problems: Vec<Vec<u64>> = Vec::new();
+    let mut operations: Vec<String> = Vec::new();
+
+    for (start, end) in groups.iter() {
+        let mut operands: Vec<u64> = Vec::new();
+
+        for col in *start..=*end {
+            let mut digits = String::new();
+
+            for row in &padded_number_rows {
+                let digit = row[col];
+                if digit.is_ascii_digit() {
+                    digits.push(digit as char);
+                }
+            }
+
+            if !digits.is_empty() {
+                let number = digits.parse().map_err(|_| {
+                    // Point at the topmost digit of the column
+                    let line_index = padded_number_rows
+                        .iter()
+                        .position(|row| row[col].is_ascii_digit())
+                        .unwrap();
+                    let row = number_rows[line_index];
+
+                    ParseError::at(line_index + 1, row, col, 1, "Cephalopod number too large")
+                })?;
+
+                operands.push(number);
+            }
+        }
+
+        // Find operator within group's columns (first non-space)
+        let operation_char = (*start..=*end)
+            .find_map(|index| {
+                let byte = operations_bytes[index];
+                if byte != b' ' {
+                    Some(byte as char)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or('+');
+
+        problems.push(operands);
+        operations.push(operation_char.to_string());
+    }
+
+    // cephalopod sheets are read right-to-left
+    problems.reverse();
+    operations.reverse();
+
+    Ok((problems, operations))
+}
+
+/// Parses the worksheet either by whitespace-separated numbers or cephalopod-style digit columns
+pub fn transform_problems_into_columns(
+    math_problems: &str,
+    split_by_digits: bool,
+) -> Result<Problems, ParseError> {
+    if split_by_digits {
+        split_digit_columns(math_problems)
+    } else {
+        split_whitespace_columns(math_problems)
+    }
+}
+
+/// Sums the results of every problem, adding or multiplying its operands
+pub fn calculate_grand_total(problems: &[Vec<u64>], operations: &[String]) -> u64 {
+    problems
+        .iter()
+        .enumerate()
+        .map(|(i, p)| match operations[i].as_str() {
+            "+" => p.iter().sum::<u64>(),
+            "*" => p.iter().product::<u64>(),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The worksheet read both the human and the cephalopod way
+pub struct Worksheet {
+    pub problems: Problems,
+    pub cephalopod_problems: Problems,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = "math_problems.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Worksheet;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            problems: transform_problems_into_columns(input, false)?,
+            cephalopod_problems: transform_problems_into_columns(input, true)?,
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> Self::Answer {
+        let (problems, operations) = &worksheet.problems;
+        calculate_grand_total(problems, operations)
+    }
+
+    fn part2(worksheet: &Self::Input) -> Self::Answer {
+        let (problems, operations) = &worksheet.cephalopod_problems;
+        calculate_grand_total(problems, operations)
+    }
+}
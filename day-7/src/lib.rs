@@ -0,0 +1,199 @@
+use common::grid::{Cell, Grid};
+use common::{ParseError, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Start,
+    Empty,
+    Splitter,
+}
+
+impl Cell for Tile {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            'S' => Some(Tile::Start),
+            '.' => Some(Tile::Empty),
+            '^' => Some(Tile::Splitter),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Start => 'S',
+            Tile::Empty => '.',
+            Tile::Splitter => '^',
+        }
+    }
+}
+
+/// Parses the diagram into a grid of tiles, requiring a start `S` in the first row
+pub fn parse_manifold_diagram(manifold_diagram: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid: Grid<Tile> = Grid::parse(manifold_diagram)?;
+
+    if !grid.row(0).contains(&Tile::Start) {
+        let first_line = manifold_diagram.lines().next().unwrap_or("");
+        return Err(ParseError::at_line(
+            1,
+            first_line,
+            "No S found in first row",
+        ));
+    }
+
+    Ok(grid)
+}
+
+fn find_start_col(grid: &Grid<Tile>) -> usize {
+    grid.row(0)
+        .iter()
+        .position(|&tile| tile == Tile::Start)
+        .expect("No S found in first row!")
+}
+
+/// Number of splitters hit by the beam on its way down
+pub fn count_splits(grid: &Grid<Tile>) -> usize {
+    let height = grid.height();
+    let width = grid.width();
+
+    let start_col = find_start_col(grid);
+
+    let mut beams: Vec<usize> = vec![start_col];
+    let mut current_row: usize = 0;
+    let mut total_splits: usize = 0;
+
+    while !beams.is_empty() {
+        let next_row = current_row + 1;
+        if next_row >= height {
+            break;
+        }
+
+        let mut split_cols: HashSet<usize> = HashSet::new();
+        let mut straight_cols: HashSet<usize> = HashSet::new();
+
+        for col in beams.drain(..) {
+            if col >= width {
+                continue;
+            }
+
+            match grid[(next_row, col)] {
+                Tile::Empty | Tile::Start => {
+                    straight_cols.insert(col);
+                }
+                Tile::Splitter => {
+                    split_cols.insert(col);
+                }
+            }
+        }
+
+        total_splits += split_cols.len();
+
+        let mut next_beams: Vec<usize> = Vec::new();
+
+        for &c in &split_cols {
+            if c > 0 {
+                next_beams.push(c - 1);
+            }
+            if c + 1 < width {
+                next_beams.push(c + 1);
+            }
+        }
+
+        // From straight beams: same column
+        for &c in &straight_cols {
+            next_beams.push(c);
+        }
+
+        beams = next_beams;
+        current_row = next_row;
+    }
+
+    total_splits
+}
+
+/// Number of timelines a single particle ends up in, splitting at every splitter
+pub fn count_possible_timelines(grid: &Grid<Tile>) -> usize {
+    let height = grid.height();
+    let width = grid.width();
+
+    // Find S in first row
+    let start_col = find_start_col(grid);
+
+    // dp[row][col] = number of timelines at cell (r, c)
+    let mut display: Grid<usize> = Grid::filled(width, height, 0);
+    display[(0, start_col)] = 1;
+
+    for row in 0..height {
+        for col in 0..width {
+            let ways = display[(row, col)];
+
+            if ways == 0 {
+                continue;
+            }
+
+            // Next row down
+            let next_row = row + 1;
+            if next_row >= height {
+                continue;
+            }
+
+            match grid[(next_row, col)] {
+                Tile::Empty | Tile::Start => {
+                    display[(next_row, col)] += ways;
+                }
+                Tile::Splitter => {
+                    if col > 0 {
+                        display[(next_row, col - 1)] += ways;
+                    }
+                    if col + 1 < width {
+                        display[(next_row, col + 1)] += ways;
+                    }
+                }
+            }
+        }
+    }
+
+    // Timelines that end by stepping below the last row:
+    let mut timelines: usize = display.row(height - 1).iter().sum();
+
+    // Timelines that would step sideways out of bounds from any row:
+    for row in 0..height {
+        // If the particle is at col 0 and the next cell is a splitter, left branch leaves.
+        if display[(row, 0)] > 0 && row + 1 < height && grid[(row + 1, 0)] == Tile::Splitter {
+            timelines += display[(row, 0)]; // left branch lost
+        }
+        // Similarly for the rightmost column
+        let last_col = width - 1;
+        if display[(row, last_col)] > 0
+            && row + 1 < height
+            && grid[(row + 1, last_col)] == Tile::Splitter
+        {
+            timelines += display[(row, last_col)]; // right branch lost
+        }
+    }
+
+    timelines
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = "manifold_diagram.txt";
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Grid<Tile>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_manifold_diagram(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Self::Answer {
+        count_splits(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> Self::Answer {
+        count_possible_timelines(grid)
+    }
+}